@@ -11,18 +11,54 @@ use clap::{Parser, Subcommand};
   tooler run argoproj/argo-cd --asset argocd-darwin-amd64   # Run with an explicit asset
   tooler run yamllint .                                     # Run a tool previously fetched
   tooler -v run act                                         # Run verbosely
+  tooler run nektos/act@v0.2.79 --offline                   # Run only from the local cache
+  tooler run gitlab:owner/repo@v1.0.0                       # Run a tool hosted on GitLab
+  tooler run gitea:git.example.org/owner/repo               # Run a tool on a self-hosted Gitea
+  tooler run yamllint --use-version 1.35.1                  # Override the resolved version
+  tooler run nektos/act@^0.2 --include-prereleases          # Match a range against prerelease tags too
+  tooler run nektos/act --insecure-skip-verify              # Skip checksum/signature verification for this run
 
   tooler list                                               # List all installed tools
+  tooler check                                              # Probe every tool and record its health
+  tooler doctor --format json                               # Machine-readable health report, non-zero exit if any tool is Broken
+  tooler doctor --diff baseline.json                        # Report health changes since a committed toolstate snapshot
+  tooler config set save_toolstate_path=toolstate.json       # Persist check/doctor health (and update rollbacks) into a JSON snapshot
+  tooler list --broken                                      # Show only tools that failed their last check
+  tooler remove --broken                                    # Remove every Broken/RunFail tool at once
   tooler update nektos/act                                  # Update to latest version
   tooler update yamllint                                    # Update short-name to latest version
   tooler update all                                         # Update all non-pinned tools
+  tooler outdated                                           # Show tools with a newer release
+  tooler upgrade                                            # Upgrade all outdated tools
+  tooler upgrade nektos/act --latest                        # Upgrade past a major.minor pin
   tooler pull infisical/infisical@infisical-cli/v0.41.90    # Pull complex tag without updating
   tooler remove nektos/act                                  # Remove all versions of a tool
+  tooler remove nektos/act@v0.2.79                          # Remove just that one version
+  tooler remove nektos/act --trash                          # Move a tool to trash instead
+  tooler restore nektos/act                                 # Restore a tool out of trash
+  tooler purge                                               # Permanently empty the trash
+  tooler prune                                               # Remove tools whose executable vanished, and their dangling shims
   tooler pin nektos/act@v0.2.79                           # Pin tool to specific version
+  tooler local nektos/act@v0.2.79                         # Pin tool to a version for this project only
+
+  tooler cache info                                         # Report scratch directory usage
+  tooler cache clear                                        # Delete leftover download/extract scratch
+
+  tooler shim --all                                         # (Re)generate wrapper scripts for every tool
+  tooler shim nektos/act                                    # (Re)generate the wrapper script for one tool
+  tooler shim --prune                                        # Delete wrapper scripts for removed tools
+  tooler remap-shims                                        # Regenerate shims after changing shim_dir
+  tooler uninstall nektos/act                               # Alias for `remove`
+  tooler clear-cache                                        # Alias for `cache clear`
 
   tooler config get                                         # Show all settings
   tooler config set auto_shim=true                          # Enable auto-shimming
   tooler config set shim_dir=/home/user/.local/bin          # Set shim directory
+  tooler config set verify_checksums=true                   # Require a checksum manifest on every install
+  tooler config set default_forge=gitlab                    # Resolve bare owner/repo ids on GitLab instead
+  tooler config set gpg_public_key_path=/home/user/.gnupg/tooler-trust.asc  # Verify signed releases with no checksum manifest
+  tooler config set github_token=ghp_xxxxxxxxxxxx            # Raise the GitHub API rate limit (GITHUB_TOKEN/GH_TOKEN take priority)
+  tooler config set no_system_cache=true                    # Always install into the per-user data dir, never the shared system cache
   tooler config unset shim_dir                              # Unset shim_dir (reverts to default)")]
 pub struct Cli {
     #[arg(short, long, action = clap::ArgAction::Count)]
@@ -31,6 +67,11 @@ pub struct Cli {
     #[arg(short, long)]
     pub quiet: bool,
 
+    /// Override the resolved version for the invoked tool (e.g. `1.35.1`, `^1.2`), taking
+    /// priority over a `.tooler-versions` entry but not over an explicit `tool_id@version`
+    #[arg(long, global = true)]
+    pub use_version: Option<String>,
+
     #[command(subcommand)]
     pub command: Commands,
 }
@@ -47,15 +88,44 @@ pub enum Commands {
         /// Explicitly specify asset name from the release to download
         #[arg(long)]
         asset: Option<String>,
+        /// Fail the install if the release publishes no checksum manifest
+        #[arg(long)]
+        require_checksum: bool,
+        /// Resolve entirely from the local cache, without contacting the GitHub API
+        #[arg(long)]
+        offline: bool,
+        /// Let a `@<range>` requirement (e.g. `@^1.2`) match prerelease tags too, not just the
+        /// ones the range itself names explicitly
+        #[arg(long)]
+        include_prereleases: bool,
+        /// Skip checksum/signature verification entirely, even if `verify_checksums` would
+        /// otherwise require it. For repos that publish no checksum manifest or signature
+        #[arg(long)]
+        insecure_skip_verify: bool,
     },
 
     /// List all installed tools
-    List,
+    List {
+        /// Only show tools last recorded as Broken or RunFail by `check`
+        #[arg(long)]
+        broken: bool,
+    },
 
     /// Update one or all tools
     Update {
         /// Tool to update (e.g., 'owner/repo' or 'tool-name'), or 'all' to update all
         tool_id: Option<String>,
+        /// Fail the update if the release publishes no checksum manifest
+        #[arg(long)]
+        require_checksum: bool,
+        /// Skip checksum/signature verification entirely, even if `verify_checksums` would
+        /// otherwise require it. For repos that publish no checksum manifest or signature
+        #[arg(long)]
+        insecure_skip_verify: bool,
+        /// Adopt the new version even if it regresses a previously-`Working` tool to
+        /// `RunFail`/`Broken`, instead of quarantining it and keeping the old version active
+        #[arg(long)]
+        no_rollback: bool,
     },
 
     /// Pull latest version of a tool without updating existing installation
@@ -64,28 +134,126 @@ pub enum Commands {
         tool_id: String,
     },
 
+    /// Show installed tools that have a newer release available
+    Outdated {
+        /// Compare against the absolute newest release, ignoring partial-version pins
+        #[arg(long)]
+        latest: bool,
+    },
+
+    /// Upgrade one or all outdated tools
+    Upgrade {
+        /// Tool to upgrade (e.g., 'owner/repo' or 'tool-name'), or omit to upgrade all outdated tools
+        tool_id: Option<String>,
+        /// Upgrade to the absolute newest release, ignoring partial-version pins
+        #[arg(long)]
+        latest: bool,
+        /// Adopt the new version even if it regresses a previously-`Working` tool to
+        /// `RunFail`/`Broken`, instead of quarantining it and keeping the old version active
+        #[arg(long)]
+        no_rollback: bool,
+    },
+
     /// Remove an installed tool
+    #[command(alias = "uninstall")]
     Remove {
-        /// Tool to remove (e.g., 'owner/repo')
+        /// Tool to remove. 'owner/repo@v1.2.3' removes only that version; 'owner/repo' (no
+        /// '@') removes every installed version. Omit when using `--broken`
+        tool_id: Option<String>,
+        /// Move the tool to trash instead of deleting it, so it can later be `restore`d
+        #[arg(long)]
+        trash: bool,
+        /// Remove every tool currently recorded as Broken or RunFail by the last `check`
+        #[arg(long, conflicts_with = "tool_id")]
+        broken: bool,
+    },
+
+    /// Check whether each configured tool's executable still resolves and runs
+    Check,
+
+    /// Probe every installed tool and print a machine-readable health report, exiting non-zero
+    /// if any tool is Broken (mirrors rustc's toolstate tracking)
+    Doctor {
+        /// Output format: 'text' (default), 'json', or 'yaml'
+        #[arg(long, default_value = "text")]
+        format: String,
+        /// Compare against a previous toolstate snapshot (as written by `save_toolstate_path`)
+        /// and report which tools regressed, recovered, appeared, or disappeared, instead of
+        /// printing the usual health report
+        #[arg(long)]
+        diff: Option<String>,
+    },
+
+    /// (Re)generate wrapper scripts in `shim_dir` so installed tools are found on PATH
+    Shim {
+        /// Tool to (re)generate a shim for (e.g. 'owner/repo' or 'tool-name')
+        tool_id: Option<String>,
+        /// (Re)generate shims for every installed tool
+        #[arg(long)]
+        all: bool,
+        /// Delete shims in `shim_dir` whose underlying tool is no longer installed
+        #[arg(long)]
+        prune: bool,
+    },
+
+    /// Restore a tool previously removed with `remove --trash`
+    Restore {
+        /// Tool to restore (e.g., 'owner/repo')
         tool_id: String,
     },
 
+    /// Permanently empty the trash
+    Purge,
+
+    /// Remove every tool whose executable no longer exists on disk, and clean up its dangling
+    /// shim symlink, without requiring `check` to have been run first
+    Prune,
+
     /// Pin a tool to a specific version
     Pin {
         /// Tool to pin (e.g., 'owner/repo@version')
         tool_id: String,
     },
 
+    /// Pin a tool to a specific version for just the current project, by writing/updating the
+    /// nearest `.tooler-versions` file
+    Local {
+        /// Tool to pin (e.g., 'owner/repo@version')
+        tool_id: String,
+    },
+
     /// Manage tooler's configuration
     Config {
         #[command(subcommand)]
         action: ConfigAction,
     },
 
+    /// Manage the download/extract scratch directory
+    Cache {
+        #[command(subcommand)]
+        action: CacheAction,
+    },
+
+    /// Delete every leftover entry in the scratch directory, leaving installed tools untouched
+    /// (shorthand for `cache clear`)
+    ClearCache,
+
+    /// (Re)generate wrapper scripts for every non-pinned installed tool (shorthand for
+    /// `shim --all`, skipping tools pinned to a specific version)
+    RemapShims,
+
     /// Show the current version
     Version,
 }
 
+#[derive(Subcommand)]
+pub enum CacheAction {
+    /// Report the size and entry count of the scratch directory
+    Info,
+    /// Delete every leftover entry in the scratch directory
+    Clear,
+}
+
 #[derive(Subcommand)]
 pub enum ConfigAction {
     /// Get a configuration setting
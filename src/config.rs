@@ -1,7 +1,11 @@
 use crate::types::*;
 use anyhow::{Context, Result};
+use fs2::FileExt;
+use serde_json::Value;
+use sha2::{Digest, Sha256};
 
 use std::fs;
+use std::io::Write;
 use std::path::PathBuf;
 
 pub const APP_NAME: &str = "tooler";
@@ -32,13 +36,116 @@ pub fn get_tooler_config_file_path() -> Result<PathBuf> {
     Ok(path)
 }
 
-pub fn get_tooler_tools_dir() -> Result<PathBuf> {
-    let path = get_user_data_dir()?.join(TOOLS_DIR_NAME);
+/// Whether `TOOLER_CI`/`CI` env vars mark this as an ephemeral CI runner, where tools should
+/// always land in the per-user data dir rather than contending over a shared system cache.
+fn running_in_ci() -> bool {
+    std::env::var("TOOLER_CI").map(|v| v == "1").unwrap_or(false)
+        || std::env::var("CI").map(|v| v == "1" || v.eq_ignore_ascii_case("true")).unwrap_or(false)
+}
+
+/// The platform-conventional location for a system-wide, shared-across-users tools directory,
+/// or `None` when the platform has no such convention.
+fn get_system_tools_base_dir() -> Option<PathBuf> {
+    if cfg!(windows) {
+        std::env::var_os("ProgramData").map(|dir| PathBuf::from(dir).join(APP_NAME))
+    } else {
+        Some(PathBuf::from("/usr/local/share").join(APP_NAME))
+    }
+}
+
+/// Whether `dir` (or its nearest existing ancestor, if `dir` doesn't exist yet) can actually be
+/// written to, so an unprivileged invocation doesn't fail outright trying to use a shared
+/// system location it has no permission on.
+fn is_writable(dir: &std::path::Path) -> bool {
+    if dir.exists() {
+        let probe = dir.join(".tooler-write-test");
+        let writable = fs::File::create(&probe).is_ok();
+        let _ = fs::remove_file(&probe);
+        writable
+    } else {
+        dir.parent().is_some_and(is_writable)
+    }
+}
+
+/// Whether the shared system tools directory is safe to trust with other users' installs: it
+/// doesn't exist yet (nothing to have been tampered with), or it exists and is owned by root or
+/// the current user and isn't group/world-writable. Checksum/signature verification only runs at
+/// install time, so a shared directory any other local account can write to would let them swap
+/// in a tampered binary that every other user then silently runs on their next `tooler run`.
+#[cfg(unix)]
+fn is_trusted_shared_dir(dir: &std::path::Path) -> bool {
+    use std::os::unix::fs::MetadataExt;
+
+    let metadata = match dir.metadata() {
+        Ok(m) => m,
+        Err(_) => return true, // doesn't exist yet; nothing to distrust
+    };
+
+    // The user's own home directory is guaranteed to be owned by the current user, so its uid
+    // stands in for "current user" without a direct getuid() syscall.
+    let current_uid = dirs::home_dir().and_then(|home| home.metadata().ok()).map(|m| m.uid());
+
+    let owned_by_trusted_user = metadata.uid() == 0 || Some(metadata.uid()) == current_uid;
+    let group_or_world_writable = metadata.mode() & 0o022 != 0;
+
+    owned_by_trusted_user && !group_or_world_writable
+}
+
+#[cfg(not(unix))]
+fn is_trusted_shared_dir(_dir: &std::path::Path) -> bool {
+    true
+}
+
+/// Base directory installed tool executables live under: a system-wide directory shared across
+/// every user on the machine, when one exists, is writable, and hasn't been opted out of via
+/// `no_system_cache` or CI detection; the per-user data dir otherwise. Falling back to the
+/// per-user data dir is silent when the shared directory is unwritable or opted out of; a
+/// writable shared directory that fails [`is_trusted_shared_dir`] logs a warning first.
+pub fn get_tooler_tools_dir(settings: &ToolerSettings) -> Result<PathBuf> {
+    let use_system_cache = !settings.no_system_cache && !running_in_ci();
+
+    let path = match use_system_cache.then(get_system_tools_base_dir).flatten() {
+        Some(dir) if is_writable(&dir) && is_trusted_shared_dir(&dir) => {
+            tracing::info!("Using system-wide shared tools directory: {}", dir.display());
+            dir.join(TOOLS_DIR_NAME)
+        }
+        Some(dir) if is_writable(&dir) => {
+            tracing::warn!(
+                "Shared tools directory {} is writable by more than its owner; falling back to the per-user data dir",
+                dir.display()
+            );
+            get_user_data_dir()?.join(TOOLS_DIR_NAME)
+        }
+        _ => get_user_data_dir()?.join(TOOLS_DIR_NAME),
+    };
+
     tracing::debug!("Tools directory: {}", path.display());
     fs::create_dir_all(&path)?;
     Ok(path)
 }
 
+pub const CACHE_DIR_NAME: &str = "cache";
+
+/// Scratch directory for in-progress downloads and archive extraction. Kept inside tooler's
+/// own data directory (rather than the OS temp dir) so a crashed or killed install leaves
+/// behind something `cache clear`/`cache info` can find and report on.
+pub fn get_tooler_cache_dir() -> Result<PathBuf> {
+    let path = get_user_data_dir()?.join(CACHE_DIR_NAME);
+    tracing::debug!("Cache directory: {}", path.display());
+    fs::create_dir_all(&path)?;
+    Ok(path)
+}
+
+/// Key of the document-level field holding the SHA-256 hash of the rest of the document, so the
+/// hash rides inside the same atomically-renamed file instead of racing a separate sidecar.
+const CHECKSUM_FIELD: &str = "_checksum";
+
+fn sha256_hex(content: &str) -> String {
+    let mut hasher = Sha256::new();
+    hasher.update(content.as_bytes());
+    format!("{:x}", hasher.finalize())
+}
+
 pub fn load_tool_configs() -> Result<ToolerConfig> {
     let config_path = get_tooler_config_file_path()?;
 
@@ -49,6 +156,27 @@ pub fn load_tool_configs() -> Result<ToolerConfig> {
     let content = fs::read_to_string(&config_path)
         .with_context(|| format!("Could not read config file at {}", config_path.display()))?;
 
+    // If a hash was recorded on the last save, verify it before trusting the contents. Older
+    // config files predating this check have no recorded checksum yet, so a missing one is not
+    // an error. The checksum covers the document with the checksum field itself removed, since
+    // that's what was hashed before the field was inserted on save.
+    if let Ok(mut document) = serde_json::from_str::<Value>(&content) {
+        if let Some(doc) = document.as_object_mut() {
+            if let Some(expected_hash) =
+                doc.remove(CHECKSUM_FIELD).and_then(|v| v.as_str().map(str::to_string))
+            {
+                let unhashed = serde_json::to_string_pretty(&document)?;
+                let actual_hash = sha256_hex(&unhashed);
+                if actual_hash != expected_hash {
+                    return Err(anyhow::anyhow!(
+                        "Config file at {} does not match its recorded checksum; it may be corrupt or truncated",
+                        config_path.display()
+                    ));
+                }
+            }
+        }
+    }
+
     let mut config: ToolerConfig = match serde_json::from_str(&content) {
         Ok(config) => config,
         Err(e) => {
@@ -93,6 +221,16 @@ pub fn load_tool_configs() -> Result<ToolerConfig> {
     Ok(config)
 }
 
+/// Persist `config`, touching only the document nodes that actually changed.
+///
+/// Rather than serializing `config` wholesale, this loads the config file's raw JSON
+/// document, rewrites only the tool entries that were added/changed/removed and the settings
+/// fields that differ, and leaves every other node exactly as it was read. That keeps
+/// hand-edited entries (stray fields, comments-by-convention keys, a user's own ordering)
+/// intact instead of losing them every time the CLI rewrites the file.
+///
+/// Relies on serde_json's `preserve_order` feature so object key order round-trips; without
+/// it, keys are still merged correctly but get re-sorted alphabetically on write.
 pub fn save_tool_configs(config: &ToolerConfig) -> Result<()> {
     let config_path = get_tooler_config_file_path()?;
     let config_dir = config_path
@@ -101,12 +239,269 @@ pub fn save_tool_configs(config: &ToolerConfig) -> Result<()> {
 
     fs::create_dir_all(config_dir)?;
 
-    let content = serde_json::to_string_pretty(config)?;
-    fs::write(&config_path, content)?;
+    let mut document: Value = if config_path.exists() {
+        let existing = fs::read_to_string(&config_path)
+            .with_context(|| format!("Could not read config file at {}", config_path.display()))?;
+        serde_json::from_str(&existing).unwrap_or(Value::Object(serde_json::Map::new()))
+    } else {
+        Value::Object(serde_json::Map::new())
+    };
+
+    if !document.is_object() {
+        document = Value::Object(serde_json::Map::new());
+    }
+    let doc = document
+        .as_object_mut()
+        .expect("document was just normalized to an object");
+
+    // Patch `tools`: drop entries that are gone, update ones that changed, and otherwise
+    // leave the existing JSON value for a tool completely untouched.
+    let mut tools_doc = doc
+        .get("tools")
+        .and_then(|v| v.as_object())
+        .cloned()
+        .unwrap_or_default();
+
+    tools_doc.retain(|key, _| config.tools.contains_key(key));
+
+    for (key, info) in &config.tools {
+        let new_value = serde_json::to_value(info)?;
+        let changed = tools_doc.get(key) != Some(&new_value);
+        if changed {
+            tools_doc.insert(key.clone(), new_value);
+        }
+    }
+    doc.insert("tools".to_string(), Value::Object(tools_doc));
+
+    // Patch `settings` field-by-field so unrecognized keys a user added are preserved.
+    let mut settings_doc = doc
+        .get("settings")
+        .and_then(|v| v.as_object())
+        .cloned()
+        .unwrap_or_default();
+    if let Value::Object(new_settings) = serde_json::to_value(&config.settings)? {
+        for (key, value) in new_settings {
+            if settings_doc.get(&key) != Some(&value) {
+                settings_doc.insert(key, value);
+            }
+        }
+    }
+    doc.insert("settings".to_string(), Value::Object(settings_doc));
+    doc.remove(CHECKSUM_FIELD);
+
+    // Record the new content's hash as a field in the document itself, so it's part of the same
+    // atomically-renamed file rather than a separately-racing sidecar that a crash between the
+    // two writes could leave stale next to a perfectly valid config.
+    let unhashed = serde_json::to_string_pretty(&document)?;
+    let checksum = sha256_hex(&unhashed);
+    document
+        .as_object_mut()
+        .expect("document was just normalized to an object")
+        .insert(CHECKSUM_FIELD.to_string(), Value::String(checksum));
+
+    let content = serde_json::to_string_pretty(&document)?;
+
+    // Write atomically: a crash or concurrent writer mid-write must never leave a truncated
+    // config behind. Write to a randomized temp file in the same directory, fsync it, then
+    // rename over the target — rename is atomic within a filesystem.
+    let mut temp_file = tempfile::Builder::new()
+        .prefix(".config")
+        .suffix(".json.tmp")
+        .tempfile_in(config_dir)
+        .with_context(|| "Could not create temporary config file")?;
+    temp_file.write_all(content.as_bytes())?;
+    temp_file.as_file().sync_all()?;
+    temp_file
+        .persist(&config_path)
+        .map_err(|e| anyhow::anyhow!("Could not replace config file: {}", e))?;
+
+    Ok(())
+}
+
+/// Path to the sibling lock file used to serialize config read-modify-write cycles.
+fn get_tooler_config_lock_file_path() -> Result<PathBuf> {
+    let mut path = get_tooler_config_file_path()?;
+    let file_name = format!(
+        "{}.lock",
+        path.file_name()
+            .ok_or_else(|| anyhow::anyhow!("Invalid config path"))?
+            .to_string_lossy()
+    );
+    path.set_file_name(file_name);
+    Ok(path)
+}
+
+/// An exclusive advisory lock on `config.json.lock`, held for the duration of a config
+/// read-modify-write cycle so two concurrent `tooler` invocations (e.g. a build script fanning
+/// out several `tooler run` calls) can't interleave their writes and clobber each other's
+/// changes. Released automatically on drop, so a crashed process never leaves the config
+/// permanently locked.
+pub struct ConfigLock {
+    file: fs::File,
+}
+
+impl ConfigLock {
+    pub fn acquire() -> Result<Self> {
+        let lock_path = get_tooler_config_lock_file_path()?;
+        let file = fs::OpenOptions::new()
+            .create(true)
+            .write(true)
+            .open(&lock_path)
+            .with_context(|| format!("Could not open lock file at {}", lock_path.display()))?;
+        file.lock_exclusive()
+            .with_context(|| format!("Could not acquire lock on {}", lock_path.display()))?;
+        Ok(Self { file })
+    }
+}
+
+impl Drop for ConfigLock {
+    fn drop(&mut self) {
+        if let Err(e) = FileExt::unlock(&self.file) {
+            tracing::warn!("Could not release config lock: {}", e);
+        }
+    }
+}
+
+/// Load the config, hand it to `f` for modification, then save the result — all while holding
+/// an exclusive [`ConfigLock`], so the read, the modification, and the write are never
+/// interleaved with another `tooler` process doing the same thing.
+pub fn with_config_locked<F, T>(f: F) -> Result<T>
+where
+    F: FnOnce(&mut ToolerConfig) -> Result<T>,
+{
+    let _lock = ConfigLock::acquire()?;
+    let mut config = load_tool_configs()?;
+    let result = f(&mut config)?;
+    save_tool_configs(&config)?;
+    Ok(result)
+}
+
+/// The async counterpart of [`with_config_locked`], for the install/update path where the
+/// mutation itself has to `.await` a network call. Same contract: load fresh under an exclusive
+/// lock, hand `f` the up-to-date config, save whatever it left behind. Without the fresh load
+/// under the lock, a mutation against a config snapshot taken before the lock was acquired can
+/// silently clobber another process's writes that landed in between — reloading only *after*
+/// the call is too late, since the callee may have already saved its own (stale-based) changes.
+pub async fn with_config_locked_async<F, Fut, T>(f: F) -> Result<T>
+where
+    F: FnOnce(&mut ToolerConfig) -> Fut,
+    Fut: std::future::Future<Output = Result<T>>,
+{
+    let _lock = ConfigLock::acquire()?;
+    let mut config = load_tool_configs()?;
+    let result = f(&mut config).await?;
+    save_tool_configs(&config)?;
+    Ok(result)
+}
+
+pub const TRASH_FILE_NAME: &str = "trash.json";
+
+pub fn get_tooler_trash_file_path() -> Result<PathBuf> {
+    let path = get_user_config_dir()?.join(TRASH_FILE_NAME);
+    tracing::debug!("Trash file path: {}", path.display());
+    Ok(path)
+}
+
+pub fn load_trash() -> Result<TrashStore> {
+    let trash_path = get_tooler_trash_file_path()?;
+
+    if !trash_path.exists() {
+        return Ok(TrashStore::default());
+    }
+
+    let content = fs::read_to_string(&trash_path)
+        .with_context(|| format!("Could not read trash file at {}", trash_path.display()))?;
 
+    serde_json::from_str(&content).with_context(|| "Could not parse trash file as JSON")
+}
+
+pub fn save_trash(trash: &TrashStore) -> Result<()> {
+    let trash_path = get_tooler_trash_file_path()?;
+    let content = serde_json::to_string_pretty(trash)?;
+    fs::write(&trash_path, content)?;
     Ok(())
 }
 
+pub const PROJECT_VERSIONS_FILE_NAME: &str = ".tooler-versions";
+
+/// Walk up from the current directory looking for a `.tooler-versions` file, and return the
+/// version pinned for `tool_name` in the nearest one found, if any.
+///
+/// Each line is `tool-name version`; blank lines and `#`-prefixed comments are ignored. Lets a
+/// repo pin e.g. `yamllint 1.35.1` for everyone who runs `tooler` inside that tree.
+pub fn find_project_version(tool_name: &str) -> Option<String> {
+    let mut dir = std::env::current_dir().ok()?;
+    loop {
+        let candidate = dir.join(PROJECT_VERSIONS_FILE_NAME);
+        if candidate.is_file() {
+            let content = fs::read_to_string(&candidate).ok()?;
+            return content.lines().find_map(|line| {
+                let line = line.trim();
+                if line.is_empty() || line.starts_with('#') {
+                    return None;
+                }
+                let (name, version) = line.split_once(char::is_whitespace)?;
+                (name == tool_name).then(|| version.trim().to_string())
+            });
+        }
+        if !dir.pop() {
+            return None;
+        }
+    }
+}
+
+/// Find the nearest `.tooler-versions` file by walking up from the current directory, without
+/// reading it. Used by [`write_project_version`] to update an existing file in place rather
+/// than always creating a fresh one in the current directory.
+fn find_nearest_project_versions_file() -> Option<PathBuf> {
+    let mut dir = std::env::current_dir().ok()?;
+    loop {
+        let candidate = dir.join(PROJECT_VERSIONS_FILE_NAME);
+        if candidate.is_file() {
+            return Some(candidate);
+        }
+        if !dir.pop() {
+            return None;
+        }
+    }
+}
+
+/// Write (or update) a `tool-name version` pin in the nearest `.tooler-versions` file, creating
+/// one in the current directory if no ancestor already has one. Backs `tooler local`, the
+/// project-scoped counterpart to `tooler pin`.
+pub fn write_project_version(tool_name: &str, version: &str) -> Result<PathBuf> {
+    let path = find_nearest_project_versions_file()
+        .unwrap_or_else(|| PathBuf::from(PROJECT_VERSIONS_FILE_NAME));
+
+    let existing = fs::read_to_string(&path).unwrap_or_default();
+    let mut found = false;
+    let mut lines: Vec<String> = existing
+        .lines()
+        .map(|line| {
+            let trimmed = line.trim();
+            if !trimmed.is_empty() && !trimmed.starts_with('#') {
+                if let Some((name, _)) = trimmed.split_once(char::is_whitespace) {
+                    if name == tool_name {
+                        found = true;
+                        return format!("{} {}", tool_name, version);
+                    }
+                }
+            }
+            line.to_string()
+        })
+        .collect();
+
+    if !found {
+        lines.push(format!("{} {}", tool_name, version));
+    }
+
+    let mut content = lines.join("\n");
+    content.push('\n');
+    fs::write(&path, content)
+        .with_context(|| format!("Could not write project versions file at {}", path.display()))?;
+    Ok(path)
+}
+
 pub fn normalize_key(key: &str) -> String {
     key.replace('-', "_")
         .chars()
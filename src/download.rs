@@ -1,26 +1,99 @@
 use crate::platform::get_system_info;
 use anyhow::{anyhow, Result};
 use flate2::read::GzDecoder;
-use indicatif::{ProgressBar, ProgressStyle};
+use indicatif::{MultiProgress, ProgressBar, ProgressStyle};
 use std::fs;
-use std::io::{self, Write};
+use std::io::{self, IsTerminal, Read, Write};
 use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Mutex;
+use std::time::Duration;
 use tar::Archive;
 use walkdir::WalkDir;
 
+static QUIET: AtomicBool = AtomicBool::new(false);
+
+/// Set once at startup from `--quiet`, so every progress bar/spinner in this module can check
+/// it without threading a flag through every download/install call.
+pub fn set_quiet(quiet: bool) {
+    QUIET.store(quiet, Ordering::Relaxed);
+}
+
+pub fn is_quiet() -> bool {
+    QUIET.load(Ordering::Relaxed)
+}
+
+/// Whether a progress bar/spinner should actually render: never under `--quiet`, and never
+/// when stderr (where indicatif draws) isn't an actual terminal, so piped/CI output stays clean.
+fn progress_visible() -> bool {
+    !QUIET.load(Ordering::Relaxed) && std::io::stderr().is_terminal()
+}
+
+/// The shared `MultiProgress` `Update all` installs into, so every tool's download bar lines up
+/// under one multi-bar area instead of each tool clobbering the previous one's line. `None`
+/// outside of a multi-tool operation, in which case bars render standalone.
+static CURRENT_MULTI_PROGRESS: Mutex<Option<MultiProgress>> = Mutex::new(None);
+
+pub fn set_multi_progress(multi: Option<MultiProgress>) {
+    *CURRENT_MULTI_PROGRESS.lock().unwrap() = multi;
+}
+
+fn current_multi_progress() -> Option<MultiProgress> {
+    CURRENT_MULTI_PROGRESS.lock().unwrap().clone()
+}
+
+/// A spinner for a step with no known size (e.g. resolving a release via the GitHub API),
+/// hidden under `--quiet` or when not attached to a terminal.
+pub fn spinner(message: &str) -> ProgressBar {
+    if !progress_visible() {
+        return ProgressBar::hidden();
+    }
+    let pb = match current_multi_progress() {
+        Some(multi) => multi.add(ProgressBar::new_spinner()),
+        None => ProgressBar::new_spinner(),
+    };
+    pb.set_style(
+        ProgressStyle::default_spinner()
+            .template("{spinner:.green} {msg}")
+            .unwrap(),
+    );
+    pb.set_message(message.to_string());
+    pb.enable_steady_tick(Duration::from_millis(100));
+    pb
+}
+
+/// Whether `asset_name` carries a filename extension `extract_archive` knows how to unpack,
+/// as opposed to a direct executable (or `.whl`, handled separately by the Python installer).
+/// Mislabeled assets still extract correctly since `extract_archive` itself sniffs magic bytes;
+/// this only decides which code path an install takes before the file is even downloaded.
+pub fn looks_like_archive_name(asset_name: &str) -> bool {
+    let name = asset_name.to_lowercase();
+    [".tar.gz", ".tgz", ".tar.xz", ".tar.zst", ".tar.bz2", ".zip", ".7z", ".gz", ".xz", ".zst", ".bz2"]
+        .iter()
+        .any(|ext| name.ends_with(ext))
+}
+
 pub async fn download_file(url: &str, local_path: &Path) -> Result<()> {
     tracing::info!("Downloading {}...", local_path.file_name().unwrap().to_string_lossy());
-    
+
     let response = reqwest::get(url).await?;
     let total_size = response.content_length().unwrap_or(0);
-    
-    let pb = ProgressBar::new(total_size);
-    pb.set_style(
-        ProgressStyle::default_bar()
-            .template("{spinner:.green} [{elapsed_precise}] [{bar:40.cyan/blue}] {bytes}/{total_bytes} ({eta})")
-            .unwrap()
-            .progress_chars("#>-")
-    );
+
+    let pb = if progress_visible() {
+        let bar = ProgressBar::new(total_size);
+        bar.set_style(
+            ProgressStyle::default_bar()
+                .template("{spinner:.green} [{elapsed_precise}] [{bar:40.cyan/blue}] {bytes}/{total_bytes} ({eta})")
+                .unwrap()
+                .progress_chars("#>-")
+        );
+        match current_multi_progress() {
+            Some(multi) => multi.add(bar),
+            None => bar,
+        }
+    } else {
+        ProgressBar::hidden()
+    };
 
     let mut file = fs::File::create(local_path)?;
     let mut downloaded = 0u64;
@@ -38,20 +111,103 @@ pub async fn download_file(url: &str, local_path: &Path) -> Result<()> {
     Ok(())
 }
 
+/// A single-stream compression wrapping either a tar archive or a bare file.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Compression {
+    Gzip,
+    Xz,
+    Zstd,
+    Bzip2,
+}
+
+/// What `extract_archive` actually found once it looked past the filename, from sniffing the
+/// downloaded file's magic bytes.
+enum ArchiveKind {
+    Zip,
+    SevenZ,
+    Tar(Compression),
+    /// A single compressed stream that isn't a tar, e.g. a release shipping the raw binary
+    /// gzipped or xz'd with no container around it.
+    CompressedFile(Compression),
+    /// No recognized archive signature: the downloaded file already is the executable.
+    Raw,
+}
+
+/// Sniff `path`'s magic bytes to determine how to extract it, ignoring its filename extension
+/// so mislabeled assets (e.g. a `.tar.gz` that's actually a bare binary) still work.
+fn sniff_archive_kind(path: &Path) -> Result<ArchiveKind> {
+    let mut header = [0u8; 6];
+    let mut file = fs::File::open(path)?;
+    let read = file.read(&mut header)?;
+    let header = &header[..read];
+
+    if header.starts_with(b"PK\x03\x04") || header.starts_with(b"PK\x05\x06") {
+        return Ok(ArchiveKind::Zip);
+    }
+    if header.starts_with(&[0x37, 0x7A, 0xBC, 0xAF, 0x27, 0x1C]) {
+        return Ok(ArchiveKind::SevenZ);
+    }
+
+    let compression = if header.starts_with(&[0x1F, 0x8B]) {
+        Some(Compression::Gzip)
+    } else if header.starts_with(&[0xFD, 0x37, 0x7A, 0x58, 0x5A, 0x00]) {
+        Some(Compression::Xz)
+    } else if header.starts_with(&[0x28, 0xB5, 0x2F, 0xFD]) {
+        Some(Compression::Zstd)
+    } else if header.starts_with(b"BZh") {
+        Some(Compression::Bzip2)
+    } else {
+        None
+    };
+
+    let Some(compression) = compression else {
+        return Ok(ArchiveKind::Raw);
+    };
+
+    if decompressed_stream_is_tar(path, compression)? {
+        Ok(ArchiveKind::Tar(compression))
+    } else {
+        Ok(ArchiveKind::CompressedFile(compression))
+    }
+}
+
+/// Decompress just enough of `path` to see whether it wraps a tar archive (tar headers carry
+/// the `ustar` magic at byte offset 257) or a single uncontained file.
+fn decompressed_stream_is_tar(path: &Path, compression: Compression) -> Result<bool> {
+    let mut header = [0u8; 262];
+    let file = fs::File::open(path)?;
+    let read = match compression {
+        Compression::Gzip => GzDecoder::new(file).read(&mut header)?,
+        Compression::Xz => xz2::read::XzDecoder::new(file).read(&mut header)?,
+        Compression::Zstd => zstd::stream::read::Decoder::new(file)?.read(&mut header)?,
+        Compression::Bzip2 => bzip2::read::BzDecoder::new(file).read(&mut header)?,
+    };
+    Ok(read >= 262 && &header[257..262] == b"ustar")
+}
+
+fn open_decompressed(path: &Path, compression: Compression) -> Result<Box<dyn Read>> {
+    let file = fs::File::open(path)?;
+    Ok(match compression {
+        Compression::Gzip => Box::new(GzDecoder::new(file)),
+        Compression::Xz => Box::new(xz2::read::XzDecoder::new(file)),
+        Compression::Zstd => Box::new(zstd::stream::read::Decoder::new(file)?),
+        Compression::Bzip2 => Box::new(bzip2::read::BzDecoder::new(file)),
+    })
+}
+
 pub fn extract_archive(archive_path: &Path, extract_dir: &Path, tool_name: &str) -> Result<PathBuf> {
     tracing::info!("Extracting {}...", archive_path.file_name().unwrap().to_string_lossy());
-    
+
     let system_info = get_system_info();
-    
-    if archive_path.extension().and_then(|s| s.to_str()) == Some("zip") {
-        extract_zip(archive_path, extract_dir)?;
-    } else if archive_path.to_string_lossy().ends_with(".tar.gz") || 
-              archive_path.to_string_lossy().ends_with(".tgz") {
-        extract_tar_gz(archive_path, extract_dir)?;
-    } else if archive_path.to_string_lossy().ends_with(".tar.xz") {
-        extract_tar_xz(archive_path, extract_dir)?;
-    } else {
-        return Err(anyhow!("Unsupported archive format: {}", archive_path.display()));
+
+    match sniff_archive_kind(archive_path)? {
+        ArchiveKind::Zip => extract_zip(archive_path, extract_dir)?,
+        ArchiveKind::SevenZ => extract_7z(archive_path, extract_dir)?,
+        ArchiveKind::Tar(compression) => extract_tar(archive_path, extract_dir, compression)?,
+        ArchiveKind::CompressedFile(compression) => {
+            extract_single_compressed_file(archive_path, extract_dir, compression, tool_name)?
+        }
+        ArchiveKind::Raw => copy_raw_executable(archive_path, extract_dir, tool_name)?,
     }
 
     let executable_path = find_executable_in_extracted(extract_dir, tool_name, &system_info.os)
@@ -75,17 +231,17 @@ pub fn extract_archive(archive_path: &Path, extract_dir: &Path, tool_name: &str)
 fn extract_zip(archive_path: &Path, extract_dir: &Path) -> Result<()> {
     let file = fs::File::open(archive_path)?;
     let mut archive = zip::ZipArchive::new(file)?;
-    
+
     for i in 0..archive.len() {
         let mut file = archive.by_index(i)?;
         let outpath = extract_dir.join(file.name());
-        
+
         // Security check for path traversal
         if !outpath.starts_with(extract_dir) {
             tracing::warn!("Skipping malicious path in zip: {}", file.name());
             continue;
         }
-        
+
         if file.name().ends_with('/') {
             fs::create_dir_all(&outpath)?;
         } else {
@@ -96,49 +252,54 @@ fn extract_zip(archive_path: &Path, extract_dir: &Path) -> Result<()> {
             io::copy(&mut file, &mut outfile)?;
         }
     }
-    
+
     Ok(())
 }
 
-fn extract_tar_gz(archive_path: &Path, extract_dir: &Path) -> Result<()> {
-    let file = fs::File::open(archive_path)?;
-    let decoder = GzDecoder::new(file);
+fn extract_7z(archive_path: &Path, extract_dir: &Path) -> Result<()> {
+    sevenz_rust::decompress_file(archive_path, extract_dir)
+        .map_err(|e| anyhow!("Failed to extract 7z archive: {}", e))
+}
+
+fn extract_tar(archive_path: &Path, extract_dir: &Path, compression: Compression) -> Result<()> {
+    let decoder = open_decompressed(archive_path, compression)?;
     let mut archive = Archive::new(decoder);
-    
+
     for entry in archive.entries()? {
         let mut entry = entry?;
         let outpath = extract_dir.join(entry.path()?);
-        
+
         // Security check for path traversal
         if !outpath.starts_with(extract_dir) {
             tracing::warn!("Skipping malicious path in tar: {:?}", entry.path()?);
             continue;
         }
-        
+
         entry.unpack(&outpath)?;
     }
-    
+
     Ok(())
 }
 
-fn extract_tar_xz(archive_path: &Path, extract_dir: &Path) -> Result<()> {
-    let file = fs::File::open(archive_path)?;
-    let decoder = xz2::read::XzDecoder::new(file);
-    let mut archive = Archive::new(decoder);
-    
-    for entry in archive.entries()? {
-        let mut entry = entry?;
-        let outpath = extract_dir.join(entry.path()?);
-        
-        // Security check for path traversal
-        if !outpath.starts_with(extract_dir) {
-            tracing::warn!("Skipping malicious path in tar: {:?}", entry.path()?);
-            continue;
-        }
-        
-        entry.unpack(&outpath)?;
-    }
-    
+/// A bare `.gz`/`.xz`/`.zst`/`.bz2` stream with no tar container: decompress it straight into
+/// `extract_dir` under the tool's name so the usual executable scorer can find it.
+fn extract_single_compressed_file(
+    archive_path: &Path,
+    extract_dir: &Path,
+    compression: Compression,
+    tool_name: &str,
+) -> Result<()> {
+    let mut decoder = open_decompressed(archive_path, compression)?;
+    let outpath = extract_dir.join(tool_name);
+    let mut outfile = fs::File::create(&outpath)?;
+    io::copy(&mut decoder, &mut outfile)?;
+    Ok(())
+}
+
+/// No recognized archive signature: the download already is the executable, so just place it
+/// under the tool's name in `extract_dir`.
+fn copy_raw_executable(archive_path: &Path, extract_dir: &Path, tool_name: &str) -> Result<()> {
+    fs::copy(archive_path, extract_dir.join(tool_name))?;
     Ok(())
 }
 
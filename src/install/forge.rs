@@ -0,0 +1,15 @@
+//! `Forge` abstracts over the different git hosting services a tool's releases can live on,
+//! so GitHub is one backend among several rather than the only option.
+
+use crate::types::GitHubRelease;
+use anyhow::Result;
+
+/// A git forge that publishes release metadata tooler can install from.
+pub trait Forge {
+    /// Build the API URL for fetching release metadata for `repo` (`owner/repo`) at `version`
+    /// (`None` or `"latest"`/`"default"` means the latest release).
+    fn build_release_url(&self, repo: &str, version: Option<&str>) -> String;
+
+    /// Fetch release information for `repo` at `version`.
+    async fn get_release_info(&self, repo: &str, version: Option<&str>) -> Result<GitHubRelease>;
+}
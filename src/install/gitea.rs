@@ -0,0 +1,69 @@
+//! Gitea/Forgejo API interaction module
+//!
+//! Both Gitea and Forgejo expose the same `/api/v1` release surface, so one backend covers both.
+
+use super::forge::Forge;
+use crate::types::GitHubRelease;
+use anyhow::{anyhow, Result};
+use reqwest::StatusCode;
+
+/// Build the Gitea/Forgejo API URL for fetching release information.
+///
+/// `host` is the instance's domain (e.g. `git.example.org`). `repo` is `owner/repo`.
+pub fn build_release_url(host: &str, repo: &str, version: Option<&str>) -> String {
+    match version {
+        Some(v) if v != "latest" && v != "default" => format!(
+            "https://{}/api/v1/repos/{}/releases/tags/{}",
+            host, repo, v
+        ),
+        _ => format!("https://{}/api/v1/repos/{}/releases/latest", host, repo),
+    }
+}
+
+/// Fetch release information from a Gitea/Forgejo instance's API.
+pub async fn get_release_info(host: &str, repo: &str, version: Option<&str>) -> Result<GitHubRelease> {
+    let url = build_release_url(host, repo, version);
+
+    let client = reqwest::Client::new();
+    let response = client
+        .get(&url)
+        .header("User-Agent", "tooler")
+        .send()
+        .await?;
+
+    if !response.status().is_success() {
+        if response.status() == StatusCode::NOT_FOUND {
+            return Err(anyhow!(
+                "Release '{}' not found for {} on {}",
+                version.unwrap_or("latest"),
+                repo,
+                host
+            ));
+        }
+        return Err(anyhow!(
+            "Gitea API request failed for {}: {}",
+            repo,
+            response.status()
+        ));
+    }
+
+    // Gitea's release/asset shape is already GitHub-compatible (`tag_name`,
+    // `assets: [{ name, browser_download_url }]`), so no conversion is needed.
+    let release: GitHubRelease = response.json().await?;
+    Ok(release)
+}
+
+/// The `Forge` backend for Gitea and Forgejo instances.
+pub struct GiteaForge {
+    pub host: String,
+}
+
+impl Forge for GiteaForge {
+    fn build_release_url(&self, repo: &str, version: Option<&str>) -> String {
+        build_release_url(&self.host, repo, version)
+    }
+
+    async fn get_release_info(&self, repo: &str, version: Option<&str>) -> Result<GitHubRelease> {
+        get_release_info(&self.host, repo, version).await
+    }
+}
@@ -2,36 +2,9 @@
 //!
 //! Provides functions for querying GitHub releases and constructing API URLs.
 
+use super::forge::Forge;
 use crate::types::GitHubRelease;
 use anyhow::Result;
-use reqwest::StatusCode;
-use std::error::Error;
-use std::fmt;
-
-#[derive(Debug)]
-pub enum GitHubReleaseError {
-    TagNotFound { repo: String, version: String },
-    LatestNotFound { repo: String },
-    RequestFailed { repo: String, status: StatusCode },
-}
-
-impl fmt::Display for GitHubReleaseError {
-    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        match self {
-            GitHubReleaseError::TagNotFound { repo, version } => {
-                write!(f, "Release tag '{}' not found in {}", version, repo)
-            }
-            GitHubReleaseError::LatestNotFound { repo } => {
-                write!(f, "No releases found for {}", repo)
-            }
-            GitHubReleaseError::RequestFailed { repo, status } => {
-                write!(f, "Failed to get release info for {}: {}", repo, status)
-            }
-        }
-    }
-}
-
-impl Error for GitHubReleaseError {}
 
 /// Build GitHub API URL for fetching release information
 ///
@@ -50,51 +23,106 @@ pub fn build_gh_release_url(repo: &str, version: Option<&str>) -> String {
     }
 }
 
-/// Fetch release information from GitHub API
-///
-/// # Arguments
-/// * `repo` - Repository in format "owner/repo"
-/// * `version` - Optional version (None means latest)
-pub async fn get_gh_release_info(repo: &str, version: Option<&str>) -> Result<GitHubRelease> {
-    let url = build_gh_release_url(repo, version);
+/// Turn a release-filename template like `node-v{version}-{os}-{arch}.tar.gz` into a regex
+/// that captures the version placeholder, treating `{os}`/`{arch}` as unconstrained wildcards.
+fn template_to_regex(template: &str) -> Result<regex::Regex> {
+    let mut pattern = String::from("(?i)");
+    let mut rest = template;
+    while let Some(start) = rest.find('{') {
+        pattern.push_str(&regex::escape(&rest[..start]));
+        let Some(end) = rest[start..].find('}') else {
+            pattern.push_str(&regex::escape(&rest[start..]));
+            rest = "";
+            break;
+        };
+        let placeholder = &rest[start + 1..start + end];
+        if placeholder == "version" {
+            pattern.push_str("(?P<version>[0-9][0-9A-Za-z.+_-]*)");
+        } else {
+            pattern.push_str(".+?");
+        }
+        rest = &rest[start + end + 1..];
+    }
+    pattern.push_str(&regex::escape(rest));
+    Ok(regex::Regex::new(&pattern)?)
+}
+
+/// Extract every `href` target from an HTML page, matching both quoted anchors and the bare
+/// rows Apache/nginx autoindex pages emit.
+fn extract_hrefs(html: &str) -> Vec<&str> {
+    static HREF_RE: std::sync::OnceLock<regex::Regex> = std::sync::OnceLock::new();
+    let re = HREF_RE.get_or_init(|| regex::Regex::new(r#"href\s*=\s*["']([^"']+)["']"#).unwrap());
+    re.captures_iter(html)
+        .filter_map(|c| c.get(1).map(|m| m.as_str()))
+        .collect()
+}
+
+/// Parse `html` for links matching `template` (see [`template_to_regex`]) and return the
+/// distinct version strings found, sorted descending (newest first).
+fn parse_version_links(html: &str, template: &str) -> Result<Vec<String>> {
+    let version_re = template_to_regex(template)?;
+
+    let mut seen = std::collections::HashSet::new();
+    let mut versions = Vec::new();
+    for href in extract_hrefs(html) {
+        let name = href.rsplit('/').next().unwrap_or(href);
+        let Some(captures) = version_re.captures(name) else {
+            continue;
+        };
+        let Some(version) = captures.name("version") else {
+            continue;
+        };
+        let version = version.as_str().to_string();
+        if seen.insert(version.clone()) {
+            versions.push(version);
+        }
+    }
 
+    versions.sort_by(|a, b| {
+        match (
+            semver::Version::parse(a.trim_start_matches('v')),
+            semver::Version::parse(b.trim_start_matches('v')),
+        ) {
+            (Ok(a), Ok(b)) => b.cmp(&a),
+            _ => b.cmp(a),
+        }
+    });
+    Ok(versions)
+}
+
+/// Discover available versions of a URL-hosted tool by fetching `url` (a plain HTTP directory
+/// listing or vendor download index) and matching its links against `template`, a
+/// release-filename pattern such as `node-v{version}-{os}-{arch}.tar.gz` where `{version}` is
+/// captured and any other `{placeholder}` matches loosely. Mirrors how nenv maps an archive
+/// suffix like `-{OS}-{ARCH}.{ARCHIVE_TYPE}` onto a version index.
+pub async fn discover_url_versions(url: &str, template: &str) -> Result<Vec<String>> {
     let client = reqwest::Client::new();
-    let response = client
-        .get(&url)
+    let html = client
+        .get(url)
         .header("User-Agent", "tooler")
         .send()
+        .await?
+        .text()
         .await?;
 
-    if !response.status().is_success() {
-        if response.status() == StatusCode::NOT_FOUND {
-            if let Some(v) = version {
-                return Err(GitHubReleaseError::TagNotFound {
-                    repo: repo.to_string(),
-                    version: v.to_string(),
-                }
-                .into());
-            }
-            return Err(GitHubReleaseError::LatestNotFound {
-                repo: repo.to_string(),
-            }
-            .into());
-        }
-        return Err(GitHubReleaseError::RequestFailed {
-            repo: repo.to_string(),
-            status: response.status(),
-        }
-        .into());
-    }
-
-    let release: GitHubRelease = response.json().await?;
-    Ok(release)
+    parse_version_links(&html, template)
 }
 
-/// Stub for discovering versions from URL-based tools
-///
-/// TODO: Implement directory scraping for URL-based version discovery
-pub async fn discover_url_versions(_url: &str) -> Result<Vec<String>> {
-    Ok(vec![])
+/// The `Forge` backend for github.com and GitHub Enterprise.
+pub struct GitHubForge;
+
+impl Forge for GitHubForge {
+    fn build_release_url(&self, repo: &str, version: Option<&str>) -> String {
+        build_gh_release_url(repo, version)
+    }
+
+    // Unauthenticated, no prereleases: the generic `Forge` trait has no slot for either, and
+    // every real GitHub call site in this crate goes through `install::fetch_release_info` (the
+    // real, token/prerelease-aware entry point) instead of this impl. Kept only so `GitHubForge`
+    // satisfies `Forge` alongside its GitLab/Gitea siblings.
+    async fn get_release_info(&self, repo: &str, version: Option<&str>) -> Result<GitHubRelease> {
+        super::get_gh_release_info(repo, version, false, None).await
+    }
 }
 
 #[cfg(test)]
@@ -133,4 +161,24 @@ mod tests {
             "https://api.github.com/repos/owner/repo/releases/tags/prefix/v1.0.0"
         );
     }
+
+    #[test]
+    fn test_parse_version_links_extracts_and_sorts_descending() {
+        let html = r#"
+            <a href="node-v18.2.0-linux-x64.tar.gz">node-v18.2.0-linux-x64.tar.gz</a>
+            <a href="node-v20.1.0-linux-x64.tar.gz">node-v20.1.0-linux-x64.tar.gz</a>
+            <a href="node-v20.1.0-darwin-arm64.tar.gz">node-v20.1.0-darwin-arm64.tar.gz</a>
+            <a href="SHASUMS256.txt">SHASUMS256.txt</a>
+        "#;
+
+        let versions = parse_version_links(html, "node-v{version}-{os}-{arch}.tar.gz").unwrap();
+        assert_eq!(versions, vec!["20.1.0".to_string(), "18.2.0".to_string()]);
+    }
+
+    #[test]
+    fn test_parse_version_links_ignores_non_matching_rows() {
+        let html = r#"<a href="../">Parent directory</a><a href="README.md">README.md</a>"#;
+        let versions = parse_version_links(html, "node-v{version}-{os}-{arch}.tar.gz").unwrap();
+        assert!(versions.is_empty());
+    }
 }
@@ -0,0 +1,113 @@
+//! GitLab API interaction module
+//!
+//! Provides functions for querying GitLab releases and constructing API URLs. Works against
+//! both gitlab.com and self-hosted instances: every function takes the instance's host as a
+//! separate argument from the `owner/repo` project path.
+
+use super::forge::Forge;
+use crate::types::{GitHubAsset, GitHubRelease};
+use anyhow::{anyhow, Result};
+use reqwest::StatusCode;
+use serde::Deserialize;
+
+#[derive(Debug, Deserialize)]
+struct GitLabRelease {
+    tag_name: String,
+    assets: GitLabAssets,
+}
+
+#[derive(Debug, Deserialize)]
+struct GitLabAssets {
+    links: Vec<GitLabAssetLink>,
+}
+
+#[derive(Debug, Deserialize)]
+struct GitLabAssetLink {
+    name: String,
+    url: String,
+}
+
+impl From<GitLabRelease> for GitHubRelease {
+    fn from(release: GitLabRelease) -> Self {
+        GitHubRelease {
+            tag_name: release.tag_name,
+            assets: release
+                .assets
+                .links
+                .into_iter()
+                .map(|link| GitHubAsset {
+                    name: link.name,
+                    browser_download_url: link.url,
+                })
+                .collect(),
+        }
+    }
+}
+
+/// Build the GitLab API URL for fetching release information.
+///
+/// `host` is the GitLab instance's domain (e.g. `gitlab.com` or `gitlab.example.org`).
+/// `repo` is `owner/repo`, which GitLab's API addresses via a URL-encoded project path.
+pub fn build_release_url(host: &str, repo: &str, version: Option<&str>) -> String {
+    let project = urlencoding_path(repo);
+    match version {
+        Some(v) if v != "latest" && v != "default" => format!(
+            "https://{}/api/v4/projects/{}/releases/{}",
+            host, project, v
+        ),
+        _ => format!(
+            "https://{}/api/v4/projects/{}/releases/permalink/latest",
+            host, project
+        ),
+    }
+}
+
+fn urlencoding_path(repo: &str) -> String {
+    repo.replace('/', "%2F")
+}
+
+/// Fetch release information from a GitLab instance's API.
+pub async fn get_release_info(host: &str, repo: &str, version: Option<&str>) -> Result<GitHubRelease> {
+    let url = build_release_url(host, repo, version);
+
+    let client = reqwest::Client::new();
+    let response = client
+        .get(&url)
+        .header("User-Agent", "tooler")
+        .send()
+        .await?;
+
+    if !response.status().is_success() {
+        if response.status() == StatusCode::NOT_FOUND {
+            return Err(anyhow!(
+                "Release '{}' not found for {} on {}",
+                version.unwrap_or("latest"),
+                repo,
+                host
+            ));
+        }
+        return Err(anyhow!(
+            "GitLab API request failed for {}: {}",
+            repo,
+            response.status()
+        ));
+    }
+
+    let release: GitLabRelease = response.json().await?;
+    Ok(release.into())
+}
+
+/// The `Forge` backend for gitlab.com and self-hosted GitLab instances.
+pub struct GitLabForge {
+    pub host: String,
+}
+
+impl Forge for GitLabForge {
+    fn build_release_url(&self, repo: &str, version: Option<&str>) -> String {
+        build_release_url(&self.host, repo, version)
+    }
+
+    async fn get_release_info(&self, repo: &str, version: Option<&str>) -> Result<GitHubRelease> {
+        get_release_info(&self.host, repo, version).await
+    }
+}
@@ -2,20 +2,2051 @@
 //!
 //! This module provides functionality for:
 //! - Installing and updating tools from GitHub or URLs
-//! - Recovering tools from local filesystem (self-healing)
 //! - Checking for and applying updates
 //! - Managing tool configuration (pinning, removing)
 //! - Listing installed tools
 
 // Submodules
+pub mod forge;
+pub mod gitea;
 pub mod github;
+pub mod gitlab;
 
 // Re-export GitHub API functions
-pub use github::{build_gh_release_url, discover_url_versions, get_gh_release_info};
-
-// Import from parent install.rs (temporary - will be moved to submodules)
-pub use crate::install::{
-    check_for_updates, find_highest_version, find_tool_entry, find_tool_executable,
-    install_or_update_tool, list_installed_tools, pin_tool, recover_all_installed_tools,
-    remove_tool, try_recover_tool, version_matches,
-};
+pub use github::{build_gh_release_url, discover_url_versions};
+
+pub use forge::Forge;
+pub use gitea::GiteaForge;
+pub use github::GitHubForge;
+pub use gitlab::GitLabForge;
+
+use crate::config::*;
+use crate::download::{download_file, extract_archive, looks_like_archive_name};
+use crate::platform::{find_asset_for_platform, get_system_info};
+use crate::tool_id::{looks_like_version_range, Forge as ForgeKind, ToolIdentifier};
+use crate::types::*;
+use anyhow::{anyhow, Context, Result};
+use chrono::Utc;
+use sha2::{Digest, Sha256, Sha512};
+use std::collections::HashMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+use std::sync::{Mutex, OnceLock};
+use std::time::{Duration, Instant};
+use tempfile::TempDir;
+use walkdir::WalkDir;
+
+/// Fetch release information for a GitLab/Gitea `identifier`. GitHub isn't dispatched through
+/// here: unlike GitLab/Gitea, its requests need to carry the resolved `GITHUB_TOKEN`/`GH_TOKEN`/
+/// `github_token` auth and an `include_prereleases` flag, neither of which the generic `Forge`
+/// trait threads through — see [`fetch_release_info`], the actual per-forge entry point every
+/// caller in this crate uses.
+async fn get_release_info(identifier: &ToolIdentifier) -> Result<GitHubRelease> {
+    let repo = identifier.repo_path();
+    let version = identifier.version.as_deref();
+
+    match identifier.forge {
+        ForgeKind::GitHub => {
+            unreachable!("fetch_release_info routes GitHub through get_gh_release_info directly")
+        }
+        ForgeKind::GitLab => {
+            let host = identifier.host.clone().unwrap_or_else(|| "gitlab.com".to_string());
+            GitLabForge { host }.get_release_info(&repo, version).await
+        }
+        ForgeKind::Gitea => {
+            let host = identifier
+                .host
+                .clone()
+                .ok_or_else(|| anyhow!("Gitea/Forgejo tool ids require an explicit host"))?;
+            GiteaForge { host }.get_release_info(&repo, version).await
+        }
+    }
+}
+
+/// Fetch release info for `identifier`, dispatching by forge the way every call site in this
+/// crate needs to: `ForgeKind::GitHub` goes through the authenticated GitHub API call (honoring
+/// `github_token`/`include_prereleases`); GitLab/Gitea go through their plain, unauthenticated
+/// [`get_release_info`]. `version` is passed straight through to the GitHub path (see
+/// [`get_gh_release_info`] for how `None`/`"latest"`/a specific tag are each handled); GitLab/
+/// Gitea instead take their version from `identifier.version`.
+pub async fn fetch_release_info(
+    identifier: &ToolIdentifier,
+    version: Option<&str>,
+    include_prereleases: bool,
+    github_token: Option<&str>,
+) -> Result<GitHubRelease> {
+    match identifier.forge {
+        ForgeKind::GitHub => {
+            get_gh_release_info(&identifier.repo_path(), version, include_prereleases, github_token).await
+        }
+        ForgeKind::GitLab | ForgeKind::Gitea => get_release_info(identifier).await,
+    }
+}
+
+/// Errors from the GitHub Releases API worth matching on, as opposed to the catch-all
+/// `anyhow!` used for everything else in this module.
+#[derive(Debug)]
+pub enum GitHubReleaseError {
+    /// A 403 response with `X-RateLimit-Remaining: 0`. `reset_at` is the human-readable time
+    /// (from the `X-RateLimit-Reset` header) the limit lifts.
+    RateLimited { reset_at: String },
+}
+
+impl std::fmt::Display for GitHubReleaseError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            GitHubReleaseError::RateLimited { reset_at } => write!(
+                f,
+                "GitHub API rate limit exceeded; resets at {}. Set GITHUB_TOKEN, GH_TOKEN, or the \
+                 github_token setting to raise the limit.",
+                reset_at
+            ),
+        }
+    }
+}
+
+impl std::error::Error for GitHubReleaseError {}
+
+/// Resolve the token to authenticate GitHub API requests with, preferring (in order) the
+/// `GITHUB_TOKEN` env var, the `GH_TOKEN` env var, then the persisted `github_token` setting.
+fn resolve_github_token(configured_token: Option<&str>) -> Option<String> {
+    std::env::var("GITHUB_TOKEN")
+        .ok()
+        .or_else(|| std::env::var("GH_TOKEN").ok())
+        .or_else(|| configured_token.map(str::to_string))
+        .filter(|t| !t.is_empty())
+}
+
+/// Shared `reqwest::Client` for GitHub API calls, reused across requests instead of building a
+/// fresh one (and its connection pool) per call.
+fn github_http_client() -> &'static reqwest::Client {
+    static CLIENT: OnceLock<reqwest::Client> = OnceLock::new();
+    CLIENT.get_or_init(reqwest::Client::new)
+}
+
+fn github_api_request(client: &reqwest::Client, url: &str, token: Option<&str>) -> reqwest::RequestBuilder {
+    let mut request = client
+        .get(url)
+        .header("Accept", "application/vnd.github.v3+json")
+        .header("User-Agent", "tooler/0.1.0");
+
+    if let Some(token) = token {
+        request = request.header("Authorization", format!("Bearer {}", token));
+    }
+
+    request
+}
+
+/// If `response` is a rate-limit rejection (403 with `X-RateLimit-Remaining: 0`), turn it into
+/// a [`GitHubReleaseError::RateLimited`] carrying the reset time from `X-RateLimit-Reset`.
+fn rate_limit_error(response: &reqwest::Response) -> Option<GitHubReleaseError> {
+    if response.status() != reqwest::StatusCode::FORBIDDEN {
+        return None;
+    }
+    let remaining = response
+        .headers()
+        .get("X-RateLimit-Remaining")
+        .and_then(|v| v.to_str().ok())?;
+    if remaining != "0" {
+        return None;
+    }
+    let reset_at = response
+        .headers()
+        .get("X-RateLimit-Reset")
+        .and_then(|v| v.to_str().ok())
+        .and_then(|v| v.parse::<i64>().ok())
+        .and_then(|secs| chrono::DateTime::from_timestamp(secs, 0))
+        .map(|dt: chrono::DateTime<Utc>| dt.to_rfc3339())
+        .unwrap_or_else(|| "an unknown time".to_string());
+    Some(GitHubReleaseError::RateLimited { reset_at })
+}
+
+pub async fn get_gh_release_info(
+    repo_full_name: &str,
+    version: Option<&str>,
+    include_prereleases: bool,
+    github_token: Option<&str>,
+) -> Result<GitHubRelease> {
+    let version = version.unwrap_or("latest");
+    let token = resolve_github_token(github_token);
+
+    if version != "latest" {
+        let req_text = version.trim_start_matches('v');
+        if looks_like_version_range(req_text) {
+            if let Ok(req) = semver::VersionReq::parse(req_text) {
+                return resolve_release_via_range(repo_full_name, &req, include_prereleases, token.as_deref()).await;
+            }
+        }
+    }
+
+    let url = if version == "latest" {
+        format!(
+            "https://api.github.com/repos/{}/releases/latest",
+            repo_full_name
+        )
+    } else {
+        // Smart version handling: don't add 'v' prefix for non-numeric versions like "tip", "master"
+        // but preserve existing 'v' prefixes and add 'v' for numeric versions
+        let version = if version.starts_with('v')
+            || version.chars().next().is_some_and(|c| c.is_ascii_digit())
+        {
+            version
+        } else {
+            &format!("v{}", version)
+        };
+        format!(
+            "https://api.github.com/repos/{}/releases/tags/{}",
+            repo_full_name, version
+        )
+    };
+
+    tracing::debug!("Fetching GitHub release info from: {}", url);
+
+    let response = github_api_request(github_http_client(), &url, token.as_deref())
+        .send()
+        .await?;
+
+    if !response.status().is_success() {
+        if let Some(err) = rate_limit_error(&response) {
+            return Err(err.into());
+        }
+        let status = response.status();
+        let error_text = response
+            .text()
+            .await
+            .unwrap_or_else(|_| "Unable to read error response".to_string());
+        return Err(anyhow!(
+            "GitHub API request failed: {} - {}",
+            status,
+            error_text
+        ));
+    }
+
+    let release: GitHubRelease = response.json().await?;
+    Ok(release)
+}
+
+const RELEASE_LIST_CACHE_TTL: Duration = Duration::from_secs(60);
+
+fn release_list_cache() -> &'static Mutex<HashMap<String, (Instant, Vec<GitHubRelease>)>> {
+    static CACHE: OnceLock<Mutex<HashMap<String, (Instant, Vec<GitHubRelease>)>>> = OnceLock::new();
+    CACHE.get_or_init(|| Mutex::new(HashMap::new()))
+}
+
+/// Fetch every release for `repo_full_name`, paginating through `Link: rel="next"` headers.
+/// Results are cached briefly per-repo to avoid hammering the API when resolving ranges.
+async fn list_all_releases(repo_full_name: &str, github_token: Option<&str>) -> Result<Vec<GitHubRelease>> {
+    if let Some((fetched_at, releases)) = release_list_cache().lock().unwrap().get(repo_full_name) {
+        if fetched_at.elapsed() < RELEASE_LIST_CACHE_TTL {
+            return Ok(releases.clone());
+        }
+    }
+
+    let client = github_http_client();
+    let mut releases = Vec::new();
+    let mut url = format!(
+        "https://api.github.com/repos/{}/releases?per_page=100",
+        repo_full_name
+    );
+
+    loop {
+        let response = github_api_request(client, &url, github_token).send().await?;
+
+        if !response.status().is_success() {
+            if let Some(err) = rate_limit_error(&response) {
+                return Err(err.into());
+            }
+            let status = response.status();
+            let error_text = response
+                .text()
+                .await
+                .unwrap_or_else(|_| "Unable to read error response".to_string());
+            return Err(anyhow!(
+                "GitHub API request failed: {} - {}",
+                status,
+                error_text
+            ));
+        }
+
+        let next_url = parse_next_link(response.headers());
+        let mut page: Vec<GitHubRelease> = response.json().await?;
+        releases.append(&mut page);
+
+        match next_url {
+            Some(next) => url = next,
+            None => break,
+        }
+    }
+
+    release_list_cache()
+        .lock()
+        .unwrap()
+        .insert(repo_full_name.to_string(), (Instant::now(), releases.clone()));
+
+    Ok(releases)
+}
+
+/// Extract the `rel="next"` URL from a GitHub API `Link` header, if present.
+fn parse_next_link(headers: &reqwest::header::HeaderMap) -> Option<String> {
+    let link = headers.get(reqwest::header::LINK)?.to_str().ok()?;
+    link.split(',').find_map(|part| {
+        let (url_part, rel_part) = part.split_once(';')?;
+        if rel_part.contains("rel=\"next\"") {
+            Some(url_part.trim().trim_start_matches('<').trim_end_matches('>').to_string())
+        } else {
+            None
+        }
+    })
+}
+
+/// Strip a release tag down to its bare semver text: a `v` prefix (`v1.2.3`) and/or a
+/// component prefix ending in `/` (e.g. `infisical-cli/v0.41.90`, seen in the e2e examples).
+fn normalize_release_tag(tag: &str) -> &str {
+    let tag = tag.rsplit('/').next().unwrap_or(tag);
+    tag.trim_start_matches('v')
+}
+
+/// Enumerate every release of `repo_full_name` and return the highest one matching `req`,
+/// skipping prereleases unless the requirement itself references one or `include_prereleases`
+/// was explicitly requested.
+async fn resolve_release_via_range(
+    repo_full_name: &str,
+    req: &semver::VersionReq,
+    include_prereleases: bool,
+    github_token: Option<&str>,
+) -> Result<GitHubRelease> {
+    let releases = list_all_releases(repo_full_name, github_token).await?;
+    let allow_prerelease =
+        include_prereleases || req.comparators.iter().any(|c| !c.pre.is_empty());
+
+    let mut best: Option<(semver::Version, &GitHubRelease)> = None;
+    for release in &releases {
+        let tag = normalize_release_tag(&release.tag_name);
+        let Ok(version) = semver::Version::parse(tag) else {
+            continue;
+        };
+        if !version.pre.is_empty() && !allow_prerelease {
+            continue;
+        }
+        if !req.matches(&version) {
+            continue;
+        }
+        if best.as_ref().map_or(true, |(best_version, _)| version > *best_version) {
+            best = Some((version, release));
+        }
+    }
+
+    best.map(|(_, release)| release.clone()).ok_or_else(|| {
+        anyhow!(
+            "No release of {} matches version requirement '{}'",
+            repo_full_name,
+            req
+        )
+    })
+}
+
+/// Common filenames GitHub release tooling uses for checksum manifests.
+fn is_checksum_asset_name(name: &str) -> bool {
+    let lower = name.to_lowercase();
+    lower == "checksums.txt"
+        || lower == "sha256sums"
+        || lower == "sha512sums"
+        || lower.ends_with(".sha256")
+        || lower.ends_with(".sha512")
+        || lower.ends_with("sha256sums")
+        || lower.ends_with("sha512sums")
+}
+
+/// Find a checksum manifest among the release's other assets, if one was published.
+fn find_checksum_asset<'a>(assets: &'a [GitHubAsset], asset_name: &str) -> Option<&'a GitHubAsset> {
+    assets
+        .iter()
+        .find(|a| a.name != asset_name && is_checksum_asset_name(&a.name))
+}
+
+/// Which digest algorithm a checksum manifest's own filename implies.
+fn checksum_kind_for_manifest(manifest_name: &str) -> &'static str {
+    if manifest_name.to_lowercase().contains("512") {
+        "sha512"
+    } else {
+        "sha256"
+    }
+}
+
+/// Common filenames GitHub release tooling uses for detached GPG signatures.
+fn is_signature_asset_name(name: &str) -> bool {
+    let lower = name.to_lowercase();
+    lower.ends_with(".asc") || lower.ends_with(".sig")
+}
+
+/// Find a detached signature among the release's other assets, matching `<asset>.asc`/`.sig`
+/// or `<asset_without_ext>.asc`/`.sig`.
+fn find_signature_asset<'a>(assets: &'a [GitHubAsset], asset_name: &str) -> Option<&'a GitHubAsset> {
+    assets.iter().find(|a| {
+        let signed_name = a.name.trim_end_matches(".asc").trim_end_matches(".sig");
+        a.name != asset_name
+            && is_signature_asset_name(&a.name)
+            && (signed_name == asset_name
+                || Path::new(asset_name).file_stem().and_then(|s| s.to_str()) == Some(signed_name))
+    })
+}
+
+/// Parse a checksum manifest line in either GNU (`<hex-digest>  <filename>`, tolerating the `*`
+/// binary-mode marker) or BSD (`SHA256 (<filename>) = <hex-digest>`) style, and return the
+/// digest if the line refers to `asset_name`.
+fn parse_checksum_line(line: &str, asset_name: &str) -> Option<String> {
+    let line = line.trim();
+
+    for prefix in ["SHA256", "SHA512"] {
+        if let Some(rest) = line
+            .strip_prefix(prefix)
+            .and_then(|r| r.trim_start().strip_prefix('('))
+        {
+            let (filename, rest) = rest.split_once(')')?;
+            let digest = rest.trim().trim_start_matches('=').trim();
+            let filename = Path::new(filename).file_name()?.to_str()?;
+            return (filename == asset_name).then(|| digest.to_lowercase());
+        }
+    }
+
+    let mut parts = line.splitn(2, char::is_whitespace);
+    let digest = parts.next()?;
+    let filename = parts.next()?.trim().trim_start_matches('*');
+    let filename = Path::new(filename).file_name()?.to_str()?;
+    (filename == asset_name).then(|| digest.to_lowercase())
+}
+
+fn sha256_hex(path: &Path) -> Result<String> {
+    let mut file = fs::File::open(path)?;
+    let mut hasher = Sha256::new();
+    std::io::copy(&mut file, &mut hasher)?;
+    Ok(format!("{:x}", hasher.finalize()))
+}
+
+fn sha512_hex(path: &Path) -> Result<String> {
+    let mut file = fs::File::open(path)?;
+    let mut hasher = Sha512::new();
+    std::io::copy(&mut file, &mut hasher)?;
+    Ok(format!("{:x}", hasher.finalize()))
+}
+
+/// Effective checksum-verification policy for a single install, combining the CLI
+/// `--require-checksum` flag with the persistent `verify_checksums` setting.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum ChecksumPolicy {
+    /// A checksum manifest must exist and match.
+    Require,
+    /// Verify against a manifest if one is published; proceed with a warning if not.
+    Warn,
+    /// Skip integrity verification entirely.
+    Off,
+}
+
+fn checksum_policy(
+    verify_checksums_setting: &str,
+    require_checksum: bool,
+    insecure_skip_verify: bool,
+) -> ChecksumPolicy {
+    if insecure_skip_verify {
+        return ChecksumPolicy::Off;
+    }
+    if require_checksum {
+        return ChecksumPolicy::Require;
+    }
+    match verify_checksums_setting {
+        "true" => ChecksumPolicy::Require,
+        "off" => ChecksumPolicy::Off,
+        _ => ChecksumPolicy::Warn,
+    }
+}
+
+/// Verify a downloaded asset against a sibling checksum manifest published in the same release,
+/// falling back to a detached GPG signature if no manifest exists but a public key is
+/// configured. Returns what was actually verified, for the caller to stash onto [`AssetInfo`]:
+/// `(kind, digest)` for a checksum manifest, `("gpg", <signature asset name>)` for a
+/// signature, or `None` if verification was skipped entirely.
+///
+/// When neither a manifest nor a usable signature exists, this logs a warning and proceeds
+/// unless `policy` is [`ChecksumPolicy::Require`], in which case that is a hard error.
+async fn verify_asset_checksum(
+    release_info: &GitHubRelease,
+    asset_info: &AssetInfo,
+    downloaded_path: &Path,
+    temp_dir: &Path,
+    policy: ChecksumPolicy,
+    gpg_public_key_path: Option<&str>,
+) -> Result<Option<(String, String)>> {
+    if policy == ChecksumPolicy::Off {
+        tracing::debug!("Checksum verification disabled; skipping '{}'", asset_info.name);
+        return Ok(None);
+    }
+
+    let Some(checksum_asset) = find_checksum_asset(&release_info.assets, &asset_info.name) else {
+        if let Some(key_path) = gpg_public_key_path {
+            if let Some(signature_asset) = find_signature_asset(&release_info.assets, &asset_info.name) {
+                return verify_asset_signature(signature_asset, downloaded_path, temp_dir, key_path)
+                    .await
+                    .map(Some);
+            }
+        }
+        if policy == ChecksumPolicy::Require {
+            return Err(anyhow!(
+                "No checksum file found for '{}' and checksum verification is required",
+                asset_info.name
+            ));
+        }
+        tracing::warn!(
+            "No checksum file found for '{}'; skipping integrity verification",
+            asset_info.name
+        );
+        return Ok(None);
+    };
+
+    let checksum_path = temp_dir.join(&checksum_asset.name);
+    download_file(&checksum_asset.browser_download_url, &checksum_path).await?;
+
+    let manifest = fs::read_to_string(&checksum_path)?;
+    let expected_digest = manifest
+        .lines()
+        .find_map(|line| parse_checksum_line(line, &asset_info.name))
+        .ok_or_else(|| {
+            anyhow!(
+                "Checksum manifest '{}' has no entry for '{}'",
+                checksum_asset.name,
+                asset_info.name
+            )
+        })?;
+
+    let kind = checksum_kind_for_manifest(&checksum_asset.name);
+    let actual_digest = match kind {
+        "sha512" => sha512_hex(downloaded_path)?,
+        _ => sha256_hex(downloaded_path)?,
+    };
+
+    if actual_digest.eq_ignore_ascii_case(&expected_digest) {
+        tracing::info!("Verified {} checksum for '{}'", kind, asset_info.name);
+        Ok(Some((kind.to_string(), actual_digest)))
+    } else {
+        Err(anyhow!(
+            "Checksum mismatch for '{}': expected {}, got {}",
+            asset_info.name,
+            expected_digest,
+            actual_digest
+        ))
+    }
+}
+
+/// Verify a detached GPG signature against the armored public key at `key_path`, as a
+/// second-tier fallback when a release publishes no checksum manifest.
+async fn verify_asset_signature(
+    signature_asset: &GitHubAsset,
+    downloaded_path: &Path,
+    temp_dir: &Path,
+    key_path: &str,
+) -> Result<(String, String)> {
+    let signature_path = temp_dir.join(&signature_asset.name);
+    download_file(&signature_asset.browser_download_url, &signature_path).await?;
+
+    let public_key = fs::read_to_string(key_path)
+        .map_err(|e| anyhow!("Failed to read GPG public key '{}': {}", key_path, e))?;
+    let (public_key, _) = pgp::SignedPublicKey::from_string(&public_key)
+        .map_err(|e| anyhow!("Invalid GPG public key '{}': {}", key_path, e))?;
+
+    let signature_bytes = fs::read(&signature_path)?;
+    let (signature, _) = pgp::StandaloneSignature::from_armor_single(std::io::Cursor::new(signature_bytes))
+        .or_else(|_| {
+            let bytes = fs::read(&signature_path)?;
+            pgp::StandaloneSignature::from_bytes(std::io::Cursor::new(bytes))
+        })
+        .map_err(|e| anyhow!("Failed to parse signature '{}': {}", signature_asset.name, e))?;
+
+    let mut file = fs::File::open(downloaded_path)?;
+    signature
+        .verify(&public_key, &mut file)
+        .map_err(|e| anyhow!("GPG signature verification failed for '{}': {}", signature_asset.name, e))?;
+
+    tracing::info!("Verified GPG signature '{}'", signature_asset.name);
+    Ok(("gpg".to_string(), signature_asset.name.clone()))
+}
+
+/// Resolve a tool straight from the local cache without touching the GitHub API.
+///
+/// Used for `--offline` installs (or as a fallback when the network is unavailable and the
+/// requested version is fully specified): the request is satisfied only if a matching,
+/// still-present executable is already recorded in `config`.
+fn install_from_cache_offline(
+    config: &ToolerConfig,
+    repo_full_name: &str,
+    version: Option<&str>,
+) -> Result<PathBuf> {
+    let query = match version {
+        Some(v) if v != "latest" && v != "default" => format!("{}@{}", repo_full_name, v),
+        _ => repo_full_name.to_string(),
+    };
+
+    // `find_tool_executable`'s pinned-version lookup falls back to any installed version of the
+    // same repo when the exact key isn't found (so `@latest` can reuse a pinned install) — but
+    // offline mode promises the exact pinned version or nothing, so that fallback is rejected here.
+    let pinned_version = match version {
+        Some(v) if v != "latest" && v != "default" => Some(v.trim_start_matches('v')),
+        _ => None,
+    };
+
+    match find_tool_executable(config, &query) {
+        Some(info)
+            if Path::new(&info.executable_path).exists()
+                && pinned_version.map_or(true, |v| v == info.version.trim_start_matches('v')) =>
+        {
+            tracing::info!(
+                "Using cached install of {} ({}) [offline mode]",
+                repo_full_name,
+                info.version
+            );
+            Ok(PathBuf::from(&info.executable_path))
+        }
+        _ => Err(anyhow!(
+            "Offline mode: no cached install of {} satisfying '{}' found locally",
+            repo_full_name,
+            version.unwrap_or("latest")
+        )),
+    }
+}
+
+/// Turn a (possibly forge-prefixed) repo identifier into a string safe to use as a single path
+/// component. `full_repo()` joins forge/host/author/repo with `/` and, for non-GitHub forges,
+/// a `:` prefix (e.g. `gitlab:gitlab.example.com/owner/repo`) — and `:` is not a valid path
+/// character on Windows outside the drive-letter position.
+fn dir_safe_repo_name(repo_full_name: &str) -> String {
+    repo_full_name.replace([':', '/'], "__")
+}
+
+pub async fn install_or_update_tool(
+    config: &mut ToolerConfig,
+    tool_name: &str,
+    repo_full_name: &str,
+    version: Option<&str>,
+    force_update: bool,
+    asset_override: Option<&str>,
+    require_checksum: bool,
+    offline: bool,
+    include_prereleases: bool,
+    insecure_skip_verify: bool,
+    no_rollback: bool,
+) -> Result<PathBuf> {
+    // Prevent installing a tool that would conflict with tooler-shim
+    if tool_name.to_lowercase() == "tooler-shim" {
+        return Err(anyhow!(
+            "Cannot install tool named 'tooler-shim' as it conflicts with the tooler shim system"
+        ));
+    }
+
+    if offline {
+        return install_from_cache_offline(config, repo_full_name, version);
+    }
+
+    let system_info = get_system_info();
+    let requested_version = version.unwrap_or("latest");
+
+    // Resolve which forge `repo_full_name` names before fetching release info, so a
+    // `gitlab:`/`gitea:`-prefixed identifier doesn't get sent straight to the GitHub API.
+    let requested_identifier = ToolIdentifier::parse(&format!("{}@{}", repo_full_name, requested_version))
+        .map_err(|e| anyhow!("Failed to parse tool identifier: {}", e))?;
+
+    let resolving_spinner = crate::download::spinner(&format!("Resolving {} release for {}...", requested_version, repo_full_name));
+    let fully_specified = !matches!(requested_version, "latest" | "default")
+        && !looks_like_version_range(requested_version.trim_start_matches('v'));
+    let release_info = match fetch_release_info(
+        &requested_identifier,
+        Some(requested_version),
+        include_prereleases,
+        config.settings.github_token.as_deref(),
+    )
+    .await
+    {
+        Ok(release_info) => release_info,
+        // Network unreachable and an exact version requested: serve it from the local cache.
+        Err(e)
+            if fully_specified
+                && e.downcast_ref::<reqwest::Error>().is_some_and(|re| re.is_connect() || re.is_timeout()) =>
+        {
+            resolving_spinner.finish_and_clear();
+            return install_from_cache_offline(config, repo_full_name, version).map_err(|_| e);
+        }
+        Err(e) => return Err(e),
+    };
+    resolving_spinner.finish_and_clear();
+    let actual_version = &release_info.tag_name;
+
+    let tool_identifier = ToolIdentifier::parse(&format!("{}@{}", repo_full_name, actual_version))
+        .map_err(|e| anyhow!("Failed to parse tool identifier: {}", e))?;
+    let tool_key = tool_identifier.config_key();
+
+    let tool_install_base_dir = get_tooler_tools_dir(&config.settings)?.join(format!(
+        "{}__{}",
+        dir_safe_repo_name(repo_full_name),
+        system_info.arch
+    ));
+    let tool_version_dir = tool_install_base_dir.join(actual_version);
+
+    tracing::debug!(
+        "Tool installation base directory: {}",
+        tool_install_base_dir.display()
+    );
+    tracing::debug!("Tool version directory: {}", tool_version_dir.display());
+    tracing::debug!("Looking for tool with key: {}", tool_key);
+
+    // A python-venv install is built against whatever interpreter was resolved at install
+    // time; if the host interpreter has since changed, the venv shim is stale even though
+    // nobody asked for a forced update.
+    let mut force_update = force_update;
+    if !force_update {
+        if let Some(current_info) = config.tools.get(&tool_key) {
+            if current_info.install_type == "python-venv" {
+                let current_interpreter = python_interpreter_identity();
+                if current_info.interpreter != current_interpreter {
+                    tracing::info!(
+                        "Host Python interpreter changed for {}; rebuilding virtual environment",
+                        tool_name
+                    );
+                    force_update = true;
+                }
+            }
+        }
+    }
+
+    // Check if already installed
+    if !force_update {
+        if let Some(current_info) = config.tools.get(&tool_key) {
+            tracing::debug!("Found tool info: {:?}", current_info);
+            tracing::debug!(
+                "Checking if executable exists at: {}",
+                current_info.executable_path
+            );
+
+            // If asset_override is provided, check if the specific asset exists
+            if let Some(asset_name) = asset_override {
+                let expected_asset_path = tool_version_dir.join(asset_name);
+                if expected_asset_path.exists() {
+                    tracing::info!(
+                        "Tool {} {} is already installed with asset '{}'.",
+                        tool_name,
+                        actual_version,
+                        asset_name
+                    );
+                    return Ok(PathBuf::from(&current_info.executable_path));
+                } else {
+                    tracing::info!(
+                        "Asset '{}' for {} {} not found. Re-downloading...",
+                        asset_name,
+                        tool_name,
+                        actual_version
+                    );
+                }
+            } else if Path::new(&current_info.executable_path).exists() {
+                tracing::info!(
+                    "Tool {} {} is already installed.",
+                    tool_name,
+                    actual_version
+                );
+                return Ok(PathBuf::from(&current_info.executable_path));
+            } else {
+                tracing::warn!(
+                    "Installation for {} {} is corrupted. Re-installing.",
+                    tool_name,
+                    actual_version
+                );
+            }
+        } else {
+            tracing::debug!("Tool not found in config with key: {}", tool_key);
+        }
+    }
+
+    tracing::info!("Installing/Updating {} {}...", tool_name, actual_version);
+
+    // Find suitable asset
+    let asset_info = if let Some(asset_name) = asset_override {
+        let asset = release_info
+            .assets
+            .iter()
+            .find(|a| a.name == asset_name)
+            .ok_or_else(|| {
+                anyhow!(
+                    "Specified asset '{}' not found in release assets",
+                    asset_name
+                )
+            })?;
+        Some(AssetInfo {
+            name: asset.name.clone(),
+            download_url: asset.browser_download_url.clone(),
+            checksum: None,
+            checksum_kind: None,
+        })
+    } else {
+        find_asset_for_platform(
+            &release_info.assets,
+            repo_full_name,
+            &system_info.os,
+            &system_info.arch,
+            system_info.libc.as_deref(),
+        )?
+    };
+
+    let mut asset_info = asset_info.ok_or_else(|| {
+        anyhow!(
+            "No suitable asset found for {} {} for your platform",
+            repo_full_name,
+            actual_version
+        )
+    })?;
+
+    // Clean up existing installation
+    if tool_version_dir.exists() {
+        fs::remove_dir_all(&tool_version_dir)?;
+    }
+    fs::create_dir_all(&tool_version_dir)?;
+
+    let executable_path = if asset_info.name.to_lowercase().ends_with(".whl") {
+        install_python_tool(&tool_version_dir, &asset_info.name, tool_name).await?
+    } else if looks_like_archive_name(&asset_info.name) {
+        let temp_dir = TempDir::new_in(get_tooler_cache_dir()?)?;
+        let temp_download_path = temp_dir.path().join(&asset_info.name);
+
+        download_file(&asset_info.download_url, &temp_download_path).await?;
+        if let Some((kind, digest)) = verify_asset_checksum(
+            &release_info,
+            &asset_info,
+            &temp_download_path,
+            temp_dir.path(),
+            checksum_policy(&config.settings.verify_checksums, require_checksum, insecure_skip_verify),
+            config.settings.gpg_public_key_path.as_deref(),
+        )
+        .await?
+        {
+            asset_info.checksum = Some(digest);
+            asset_info.checksum_kind = Some(kind);
+        }
+
+        // Cache downloaded file
+        let cached_asset_path = tool_version_dir.join(&asset_info.name);
+        fs::copy(&temp_download_path, &cached_asset_path)?;
+
+        extract_archive(&temp_download_path, &tool_version_dir, tool_name)?
+    } else {
+        // Direct executable
+        let temp_dir = TempDir::new_in(get_tooler_cache_dir()?)?;
+        let temp_download_path = temp_dir.path().join(&asset_info.name);
+
+        download_file(&asset_info.download_url, &temp_download_path).await?;
+        if let Some((kind, digest)) = verify_asset_checksum(
+            &release_info,
+            &asset_info,
+            &temp_download_path,
+            temp_dir.path(),
+            checksum_policy(&config.settings.verify_checksums, require_checksum, insecure_skip_verify),
+            config.settings.gpg_public_key_path.as_deref(),
+        )
+        .await?
+        {
+            asset_info.checksum = Some(digest);
+            asset_info.checksum_kind = Some(kind);
+        }
+
+        let final_binary_name = if system_info.os == "windows" {
+            format!("{}.exe", tool_name)
+        } else {
+            tool_name.to_string()
+        };
+
+        let move_target_path = tool_version_dir.join(final_binary_name);
+        fs::rename(&temp_download_path, &move_target_path)?;
+
+        // Make executable on Unix-like systems
+        if system_info.os != "windows" {
+            #[cfg(unix)]
+            {
+                use std::os::unix::fs::PermissionsExt;
+                let mut perms = fs::metadata(&move_target_path)?.permissions();
+                perms.set_mode(0o755);
+                fs::set_permissions(&move_target_path, perms)?;
+            }
+        }
+
+        tracing::info!(
+            "Installed direct executable to: {}",
+            move_target_path.display()
+        );
+        move_target_path
+    };
+
+    // Update config
+    let install_type = if asset_info.name.to_lowercase().ends_with(".whl") {
+        "python-venv".to_string()
+    } else if looks_like_archive_name(&asset_info.name) {
+        "archive".to_string()
+    } else {
+        "binary".to_string()
+    };
+
+    let interpreter = if install_type == "python-venv" {
+        python_interpreter_identity()
+    } else {
+        None
+    };
+
+    let files = installed_files(&tool_version_dir);
+
+    let tool_info = ToolInfo {
+        tool_name: tool_name.to_lowercase(),
+        repo: repo_full_name.to_string(),
+        version: actual_version.trim_start_matches('v').to_string(),
+        executable_path: executable_path.to_string_lossy().to_string(),
+        install_type,
+        pinned: requested_identifier.is_pinned(),
+        installed_at: Utc::now().to_rfc3339(),
+        last_accessed: Utc::now().to_rfc3339(),
+        interpreter,
+        health: None,
+        quarantined: false,
+        files,
+    };
+
+    // Regression guard: an update superseding a previously-working install whose freshly probed
+    // replacement comes back strictly less healthy gets quarantined instead of adopted, leaving
+    // the old (still-working) version as the active one. Versioned install dirs mean "rolling
+    // back" is just never pointing unpinned resolution at the new entry. The previous version is
+    // probed right here, immediately before the swap, rather than trusting a possibly-absent or
+    // stale `health` record from an earlier `tooler check`/`doctor` run — the ordinary
+    // update/auto-update path never calls that first, so a stored record is the exception, not
+    // the rule.
+    if force_update && !no_rollback {
+        let previous = config
+            .tools
+            .iter()
+            .filter(|(_, info)| !info.quarantined && !info.pinned && info.repo.eq_ignore_ascii_case(repo_full_name) && info.version != tool_info.version)
+            .max_by_key(|(_, info)| info.last_accessed.clone())
+            .map(|(key, info)| (key.clone(), info.clone()));
+
+        if let Some((previous_key, mut previous_info)) = previous {
+            let previous_path = Path::new(&previous_info.executable_path);
+            let previous_health = if !previous_path.exists() {
+                ToolHealth::Broken
+            } else if probe_version(previous_path).is_some() {
+                ToolHealth::Working
+            } else {
+                ToolHealth::RunFail
+            };
+            previous_info.health = Some(ToolHealthCheck {
+                state: previous_health,
+                checked_at: Utc::now().to_rfc3339(),
+            });
+            if let Some(previous_tool) = config.tools.get_mut(&previous_key) {
+                previous_tool.health = previous_info.health.clone();
+            }
+
+            if previous_health == ToolHealth::Working {
+                let new_health = if !executable_path.exists() {
+                    ToolHealth::Broken
+                } else if probe_version(&executable_path).is_some() {
+                    ToolHealth::Working
+                } else {
+                    ToolHealth::RunFail
+                };
+
+                if new_health < ToolHealth::Working {
+                    tracing::warn!(
+                        "update of {} {}->{} regressed: was working, now {:?} — keeping {}",
+                        repo_full_name,
+                        previous_info.version,
+                        tool_info.version,
+                        new_health,
+                        previous_info.version
+                    );
+
+                    let mut quarantined_info = tool_info;
+                    quarantined_info.health = Some(ToolHealthCheck {
+                        state: new_health,
+                        checked_at: Utc::now().to_rfc3339(),
+                    });
+                    quarantined_info.quarantined = true;
+                    let quarantined_version = quarantined_info.version.clone();
+                    let quarantined_key = tool_key.clone();
+                    config.tools.insert(tool_key, quarantined_info);
+
+                    if let Some(previous_tool) = config.tools.get_mut(&previous_key) {
+                        previous_tool.last_accessed = Utc::now().to_rfc3339();
+                    }
+                    save_tool_configs(config)?;
+                    let checked_at = Utc::now().to_rfc3339();
+                    write_toolstate(
+                        &config.settings,
+                        &[
+                            (quarantined_key, new_health, checked_at.clone()),
+                            (previous_key.clone(), ToolHealth::Working, checked_at),
+                        ],
+                    )?;
+
+                    return Err(anyhow!(
+                        "update of {} {}->{} regressed (was working, now {:?}); kept {} and quarantined {}",
+                        repo_full_name,
+                        previous_info.version,
+                        quarantined_version,
+                        new_health,
+                        previous_info.version,
+                        quarantined_version
+                    ));
+                }
+            }
+        }
+    }
+
+    config.tools.insert(tool_key, tool_info);
+    save_tool_configs(config)?;
+
+    tracing::info!(
+        "Successfully installed {} {} to {}",
+        tool_name,
+        actual_version,
+        executable_path.display()
+    );
+    if !crate::download::is_quiet() {
+        println!("📦 {} {} installed", tool_name, actual_version);
+    }
+
+    // Add pinning suggestion if asset was explicitly selected
+    if let Some(asset_name) = asset_override {
+        tracing::info!(
+            "Successfully installed {}@{} using asset '{}'.",
+            repo_full_name,
+            actual_version,
+            asset_name
+        );
+        tracing::info!(
+            "To use this asset by default in the future, run:\n  tooler pin {}@{}",
+            repo_full_name,
+            asset_name
+        );
+    }
+
+    Ok(executable_path)
+}
+
+/// Identify the `python3` interpreter tooler will build venvs against, so a later interpreter
+/// upgrade/switch can be detected by comparing this string across installs.
+fn python_interpreter_identity() -> Option<String> {
+    let output = Command::new("python3")
+        .args(["-c", "import sys; print(sys.base_prefix, sys.version)"])
+        .output()
+        .ok()?;
+
+    if !output.status.success() {
+        return None;
+    }
+
+    let identity = String::from_utf8_lossy(&output.stdout).trim().to_string();
+    if identity.is_empty() {
+        None
+    } else {
+        Some(identity)
+    }
+}
+
+async fn install_python_tool(
+    tool_dir: &Path,
+    wheel_path: &str,
+    tool_name: &str,
+) -> Result<PathBuf> {
+    tracing::info!("Setting up Python environment for {}...", tool_name);
+
+    let venv_path = tool_dir.join(".venv");
+
+    // Create virtual environment
+    let output = Command::new("python3")
+        .args(["-m", "venv", &venv_path.to_string_lossy()])
+        .output()?;
+
+    if !output.status.success() {
+        return Err(anyhow!(
+            "Failed to create virtual environment: {}",
+            String::from_utf8_lossy(&output.stderr)
+        ));
+    }
+
+    let pip_exec = if cfg!(windows) {
+        venv_path.join("Scripts").join("pip.exe")
+    } else {
+        venv_path.join("bin").join("pip")
+    };
+
+    // Upgrade pip
+    let output = Command::new(&pip_exec)
+        .args(["install", "--upgrade", "pip"])
+        .output()?;
+
+    if !output.status.success() {
+        return Err(anyhow!(
+            "Failed to upgrade pip: {}",
+            String::from_utf8_lossy(&output.stderr)
+        ));
+    }
+
+    // Install wheel
+    tracing::info!("Installing local wheel {}...", wheel_path);
+    let output = Command::new(&pip_exec)
+        .args(["install", wheel_path])
+        .output()?;
+
+    if !output.status.success() {
+        return Err(anyhow!(
+            "Failed to install wheel: {}",
+            String::from_utf8_lossy(&output.stderr)
+        ));
+    }
+
+    // Create shim script
+    let shim_path = tool_dir.join(tool_name);
+    let shim_content = if cfg!(windows) {
+        format!(
+            "@echo off\r\n\"%~dp0\\.venv\\Scripts\\{}.exe\" %*\r\n",
+            tool_name
+        )
+    } else {
+        format!(
+            "#!/bin/sh\nexec \"$(dirname \"$0\")/.venv/bin/{}\" \"$@\"\n",
+            tool_name
+        )
+    };
+
+    fs::write(&shim_path, shim_content)?;
+
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::PermissionsExt;
+        let mut perms = fs::metadata(&shim_path)?.permissions();
+        perms.set_mode(0o755);
+        fs::set_permissions(&shim_path, perms)?;
+    }
+
+    tracing::info!("Created shim script at: {}", shim_path.display());
+    Ok(shim_path)
+}
+
+/// Resolve a tool to an already-installed system binary instead of a tooler-managed copy.
+///
+/// Checks, in order: an explicit `TOOLER_<TOOLNAME>_BIN` env override, then a PATH search for
+/// `tool_name`. When `version_req` is given, the found binary's `--version` output must satisfy
+/// it (via [`version_matches`]) or the global binary is rejected. This is opt-in (gated by the
+/// `prefer_global` setting) so tooler doesn't silently defer to an unrelated PATH binary.
+pub fn find_global_tool_executable(tool_name: &str, version_req: Option<&str>) -> Option<ToolInfo> {
+    let env_key = format!("TOOLER_{}_BIN", tool_name.to_uppercase().replace('-', "_"));
+    if let Ok(override_path) = std::env::var(&env_key) {
+        let override_path = PathBuf::from(override_path);
+        if override_path.is_file() {
+            tracing::debug!("Using {} override: {}", env_key, override_path.display());
+            return Some(synthetic_global_tool_info(tool_name, &override_path));
+        }
+        tracing::warn!(
+            "{} is set to '{}' but that path does not exist",
+            env_key,
+            override_path.display()
+        );
+    }
+
+    let path = which_in_path(tool_name)?;
+
+    if let Some(version_req) = version_req {
+        match probe_version(&path) {
+            Some(reported) if version_matches(version_req, &reported) => {}
+            Some(reported) => {
+                tracing::debug!(
+                    "Global {} at {} reports version {} which doesn't satisfy '{}'",
+                    tool_name,
+                    path.display(),
+                    reported,
+                    version_req
+                );
+                return None;
+            }
+            None => {
+                tracing::debug!(
+                    "Could not determine version of global {} at {}",
+                    tool_name,
+                    path.display()
+                );
+                return None;
+            }
+        }
+    }
+
+    tracing::info!(
+        "Found {} on PATH at {}; reusing existing install",
+        tool_name,
+        path.display()
+    );
+    Some(synthetic_global_tool_info(tool_name, &path))
+}
+
+fn synthetic_global_tool_info(tool_name: &str, path: &Path) -> ToolInfo {
+    ToolInfo {
+        tool_name: tool_name.to_lowercase(),
+        repo: tool_name.to_string(),
+        version: "system".to_string(),
+        executable_path: path.to_string_lossy().to_string(),
+        install_type: "system".to_string(),
+        pinned: true,
+        installed_at: Utc::now().to_rfc3339(),
+        last_accessed: Utc::now().to_rfc3339(),
+        interpreter: None,
+        health: None,
+        quarantined: false,
+        files: Vec::new(),
+    }
+}
+
+/// A minimal PATH search for an executable named `tool_name` (or its Windows variants).
+fn which_in_path(tool_name: &str) -> Option<PathBuf> {
+    let path_var = std::env::var_os("PATH")?;
+    let candidates: Vec<String> = if cfg!(windows) {
+        vec![
+            format!("{}.exe", tool_name),
+            format!("{}.cmd", tool_name),
+            tool_name.to_string(),
+        ]
+    } else {
+        vec![tool_name.to_string()]
+    };
+
+    std::env::split_paths(&path_var).find_map(|dir| {
+        candidates
+            .iter()
+            .map(|candidate| dir.join(candidate))
+            .find(|full| full.is_file())
+    })
+}
+
+/// Run `<path> --version` and pull the first token that looks like a version number.
+fn probe_version(path: &Path) -> Option<String> {
+    let output = Command::new(path).arg("--version").output().ok()?;
+    if !output.status.success() {
+        return None;
+    }
+    let text = String::from_utf8_lossy(&output.stdout);
+    text.split_whitespace()
+        .find(|tok| {
+            tok.trim_start_matches('v')
+                .chars()
+                .next()
+                .is_some_and(|c| c.is_ascii_digit())
+        })
+        .map(|tok| tok.trim_start_matches('v').to_string())
+}
+
+pub fn find_tool_executable<'a>(
+    config: &'a ToolerConfig,
+    tool_query: &str,
+) -> Option<&'a ToolInfo> {
+    tracing::debug!("Finding tool executable for query: {}", tool_query);
+
+    let tool_identifier = ToolIdentifier::parse(tool_query).ok()?;
+    let tool_key = tool_identifier.config_key();
+
+    tracing::debug!("Parsed tool identifier: {:?}", tool_identifier);
+    tracing::debug!("Looking for tool with key: {}", tool_key);
+
+    if tool_identifier.is_pinned() {
+        // Check if it's an exact version match first
+        if let Some(exact_match) = config.tools.get(&tool_key) {
+            return Some(exact_match);
+        }
+
+        // If exact match not found, try matching by repo name with any version
+        // This handles cases like @latest when you have a specific version installed
+        let matching_tool = config
+            .tools
+            .values()
+            .find(|info| info.repo.to_lowercase() == tool_identifier.full_repo().to_lowercase());
+
+        if let Some(exact_match) = matching_tool {
+            tracing::debug!("Found tool by repo match: {}", exact_match.repo);
+            return Some(exact_match);
+        }
+
+        // Also try matching by tool name only (last part of repo)
+        let matching_by_name = config.tools.values().find(|info| {
+            info.repo
+                .to_lowercase()
+                .ends_with(&format!("/{}", tool_identifier.tool_name().to_lowercase()))
+                || info.repo.to_lowercase() == tool_identifier.tool_name().to_lowercase()
+        });
+
+        if let Some(exact_match) = matching_by_name {
+            tracing::debug!("Found tool by name match: {}", exact_match.repo);
+            return Some(exact_match);
+        }
+
+        // For backwards compatibility, also check the old : format
+        let old_key = format!(
+            "{}:{}",
+            tool_identifier.full_repo(),
+            tool_identifier.api_version()
+        );
+        if let Some(exact_match) = config.tools.get(&old_key) {
+            return Some(exact_match);
+        }
+
+        // If not found, try semver matching for partial versions
+        if let Some(requested_version) = &tool_identifier.version {
+            let matching_tools: Vec<&'a ToolInfo> = config.tools
+                .values()
+                .filter(|info| {
+                    // Match by tool name/repo first
+                    let name_matches = info.tool_name.to_lowercase() == tool_identifier.tool_name().to_lowercase() ||
+                        (tool_identifier.author != "unknown" &&
+                         info.repo.to_lowercase() == tool_identifier.full_repo().to_lowercase()) ||
+                        info.repo.to_lowercase().ends_with(&format!("/{}", tool_identifier.tool_name().to_lowercase())) ||
+                        info.repo.to_lowercase() == tool_identifier.tool_name().to_lowercase() ||
+                        // Also match if requested tool name is part of repo
+                        info.repo.to_lowercase().contains(&format!("/{}", tool_identifier.tool_name().to_lowercase()));
+
+                    tracing::trace!("Name match check for {}: {} (repo: {})",
+                        tool_identifier.tool_name(), name_matches, info.repo);
+
+                    if !name_matches {
+                        return false;
+                    }
+
+                    // Use version field from ToolInfo
+                    version_matches(requested_version, &info.version)
+                })
+                .collect();
+
+            tracing::debug!(
+                "Found {} matching tools for version {}",
+                matching_tools.len(),
+                requested_version
+            );
+
+            // Return highest version that matches
+            if !matching_tools.is_empty() {
+                return find_highest_version(matching_tools);
+            }
+        }
+
+        // If no semver match found, try exact match again (for non-semver versions like "master")
+        config.tools.get(&tool_key)
+    } else {
+        // Find matching tools for unpinned queries
+        let matching_tools: Vec<&'a ToolInfo> = config.tools
+            .values()
+            .filter(|info| !info.quarantined)
+            .filter(|info| {
+                // Match by tool name (e.g., "k9s" matches "derailed/k9s")
+                info.tool_name.to_lowercase() == tool_identifier.tool_name().to_lowercase() ||
+                // Match by full repo if specified (e.g., "derailed/k9s" matches "derailed/k9s")
+                (tool_identifier.author != "unknown" &&
+                 info.repo.to_lowercase() == tool_identifier.full_repo().to_lowercase()) ||
+                // Match by repo name alone (e.g., "k9s" matches repo "k9s")
+                info.repo.to_lowercase().ends_with(&format!("/{}", tool_identifier.tool_name().to_lowercase())) ||
+                info.repo.to_lowercase() == tool_identifier.tool_name().to_lowercase() ||
+                // Also match if requested tool name is part of repo
+                info.repo.to_lowercase().contains(&format!("/{}", tool_identifier.tool_name().to_lowercase()))
+            })
+            .collect();
+
+        tracing::debug!("Found {} matching tools", matching_tools.len());
+
+        // Return the most recently accessed tool
+        matching_tools
+            .into_iter()
+            .max_by_key(|info| &info.last_accessed)
+    }
+}
+
+/// Check if a requested version matches an existing version
+/// Supports full semver range syntax (e.g., "^1.2.3", ">=1.0,<2.0"), bare `major.minor`
+/// prefixes (e.g., "1.5" matches "1.5.2", "1.5.0"), and exact string matches.
+fn version_matches(requested: &str, existing: &str) -> bool {
+    // Clean versions (remove 'v' prefix if present)
+    let requested_clean = requested.trim_start_matches('v');
+    let existing_clean = existing.trim_start_matches('v');
+
+    // If they're exactly the same, it's a match
+    if requested_clean == existing_clean {
+        return true;
+    }
+
+    let Ok(exist_semver) = semver::Version::parse(existing_clean) else {
+        // Non-semver versions (like "master", "tip", etc.) - exact match only
+        return requested_clean == existing_clean;
+    };
+
+    // A real range requirement (`^1.2.3`, `>=1.0,<2.0`, `*`, ...) is parsed with
+    // VersionReq regardless of how many dot components it has, so e.g. "^1.2.3"
+    // correctly matches an already-installed 1.3.0 instead of falling through to a
+    // literal string comparison.
+    if looks_like_version_range(requested_clean) {
+        return semver::VersionReq::parse(requested_clean)
+            .map(|req| req.matches(&exist_semver))
+            .unwrap_or(false);
+    }
+
+    // Try to parse as a bare version
+    if let Ok(req_semver) = semver::Version::parse(requested_clean) {
+        let req_parts = requested_clean.split('.').count();
+
+        // For partial versions like "1.5", match any 1.5.x
+        if req_parts <= 2 {
+            return req_semver.major == exist_semver.major && req_semver.minor == exist_semver.minor;
+        }
+
+        // For full versions, exact match
+        return req_semver == exist_semver;
+    }
+
+    // Partial versions that aren't valid `semver::Version` on their own (e.g. "1.5")
+    // still parse as a `VersionReq`.
+    if requested_clean.split('.').count() <= 2 {
+        if let Ok(req_req) = semver::VersionReq::parse(requested_clean) {
+            return req_req.matches(&exist_semver);
+        }
+    }
+
+    false
+}
+
+/// Find highest version among matching tools
+fn find_highest_version(tools: Vec<&ToolInfo>) -> Option<&ToolInfo> {
+    tools.into_iter().max_by(|a, b| {
+        let a_version = &a.version;
+        let b_version = &b.version;
+
+        // Clean versions
+        let a_clean = a_version.trim_start_matches('v');
+        let b_clean = b_version.trim_start_matches('v');
+
+        // Try to compare as semver
+        match (
+            semver::Version::parse(a_clean),
+            semver::Version::parse(b_clean),
+        ) {
+            (Ok(a_semver), Ok(b_semver)) => a_semver.cmp(&b_semver),
+            _ => {
+                // Fall back to string comparison for non-semver versions
+                a_clean.cmp(b_clean)
+            }
+        }
+    })
+}
+
+pub fn pin_tool(config: &mut ToolerConfig, tool_query: &str) -> Result<()> {
+    let tool_identifier =
+        ToolIdentifier::parse(tool_query).map_err(|e| anyhow!("Invalid tool identifier: {}", e))?;
+
+    // Find the tool in config using the exact version key
+    let tool_key = tool_identifier.config_key();
+
+    if let Some(mut tool_info) = config.tools.remove(&tool_key) {
+        // Mark the tool as pinned
+        tool_info.pinned = true;
+        config.tools.insert(tool_key, tool_info.clone());
+
+        // Also update @latest entry to point to this pinned version
+        let latest_key = tool_identifier.default_config_key();
+        if let Some(mut latest_tool) = config.tools.remove(&latest_key) {
+            latest_tool.pinned = true;
+            latest_tool.version = tool_info.version.clone();
+            latest_tool.executable_path = tool_info.executable_path.clone();
+            config.tools.insert(latest_key, latest_tool);
+        }
+
+        save_tool_configs(config)?;
+        tracing::info!(
+            "Successfully pinned {} to version {}",
+            tool_identifier.full_repo(),
+            tool_info.version
+        );
+        Ok(())
+    } else {
+        Err(anyhow!(
+            "Tool '{}' not found. Install it first with 'tooler install {}'",
+            tool_query,
+            tool_query
+        ))
+    }
+}
+
+/// Outcome of a [`remove_tool`] call.
+///
+/// There is deliberately no cross-tool-reference variant here: `ToolInfo` has no field naming
+/// another tool as a dependency, so there is nothing for a removal to cascade through. An
+/// earlier attempt at dependency-cascade cleanup on removal was reverted for that reason; if
+/// tools ever gain a `depends_on` edge, that cascade can be reintroduced against a real source
+/// of truth instead of a field nothing populates.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum RemovalOutcome {
+    NotFound,
+    Removed,
+}
+
+pub fn remove_tool(config: &mut ToolerConfig, tool_query: &str, trash: bool) -> Result<RemovalOutcome> {
+    // Prevent removing tooler-shim
+    if tool_query.to_lowercase() == "tooler-shim" {
+        return Err(anyhow!(
+            "Cannot remove 'tooler-shim' as it is part of the tooler system"
+        ));
+    }
+
+    let tool_identifier =
+        ToolIdentifier::parse(tool_query).map_err(|e| anyhow!("Invalid tool identifier: {}", e))?;
+    let keys_to_remove: Vec<String> = config
+        .tools
+        .keys()
+        .filter(|k| {
+            k.as_str() == tool_identifier.config_key()
+                || (!tool_query.contains('@') && !tool_query.contains(':') && {
+                    let info = &config.tools[k.as_str()];
+                    info.repo.to_lowercase() == tool_query.to_lowercase()
+                })
+        })
+        .cloned()
+        .collect();
+
+    if keys_to_remove.is_empty() {
+        return Ok(RemovalOutcome::NotFound);
+    }
+
+    if trash {
+        let mut trash_store = load_trash()?;
+        for key in &keys_to_remove {
+            if let Some(info) = config.tools.remove(key) {
+                let removed_at = Utc::now().to_rfc3339();
+                tracing::info!("Moving '{}' to trash (removed_at: {})", key, removed_at);
+                trash_store.entries.insert(
+                    removed_at.clone(),
+                    TrashEntry {
+                        key: key.clone(),
+                        info,
+                        removed_at,
+                    },
+                );
+            }
+        }
+        save_trash(&trash_store)?;
+        save_tool_configs(config)?;
+        tracing::info!("Tool(s) for '{}' moved to trash", tool_query);
+        return Ok(RemovalOutcome::Removed);
+    }
+
+    for key in &keys_to_remove {
+        if let Some(info) = config.tools.remove(key) {
+            remove_tool_install_dirs(&info, &config.settings)?;
+        }
+    }
+
+    save_tool_configs(config)?;
+    tracing::info!("Tool(s) for '{}' removed successfully", tool_query);
+    Ok(RemovalOutcome::Removed)
+}
+
+/// Walk a freshly-populated version directory and record every file it contains, so the
+/// resulting `ToolInfo.files` receipt lets `remove_tool` delete exactly what this install wrote.
+fn installed_files(tool_version_dir: &Path) -> Vec<String> {
+    WalkDir::new(tool_version_dir)
+        .into_iter()
+        .filter_map(|e| e.ok())
+        .filter(|e| e.path().is_file())
+        .map(|e| e.path().to_string_lossy().to_string())
+        .collect()
+}
+
+/// Delete exactly the files `info.files` recorded an install having written, then remove
+/// whichever of their parent directories (the version directory, and the architecture-specific
+/// directory above it) are now empty. Falls back to the version-directory convention for
+/// entries with no receipt (installed before the `files` field existed).
+fn remove_tool_install_dirs(info: &ToolInfo, settings: &ToolerSettings) -> Result<()> {
+    if !info.files.is_empty() {
+        let mut parent_dirs = Vec::new();
+        for file in &info.files {
+            let path = Path::new(file);
+            if path.exists() {
+                tracing::info!("Removing file: {}", path.display());
+                fs::remove_file(path)?;
+            }
+            if let Some(parent) = path.parent() {
+                if !parent_dirs.contains(&parent.to_path_buf()) {
+                    parent_dirs.push(parent.to_path_buf());
+                }
+            }
+        }
+        for dir in parent_dirs {
+            // Only removes a directory that's actually empty, so sibling versions/architectures
+            // for the same tool are left alone.
+            if fs::remove_dir(&dir).is_ok() {
+                if let Some(grandparent) = dir.parent() {
+                    let _ = fs::remove_dir(grandparent);
+                }
+            }
+        }
+        return Ok(());
+    }
+
+    let tool_base_dir = get_tooler_tools_dir(settings)?.join(dir_safe_repo_name(&info.repo));
+
+    // Try to remove the specific version directory first
+    if tool_base_dir.join(&info.version).exists() {
+        tracing::info!(
+            "Removing directory: {}",
+            tool_base_dir.join(&info.version).display()
+        );
+        fs::remove_dir_all(tool_base_dir.join(&info.version))?;
+    }
+
+    // Also check for architecture-specific directories
+    if let Ok(entries) = fs::read_dir(tool_base_dir.parent().unwrap_or(&tool_base_dir)) {
+        for entry in entries.flatten() {
+            let entry_path = entry.path();
+            if entry_path.is_dir() {
+                let dir_name = entry_path.file_name().unwrap_or_default().to_string_lossy();
+                if dir_name.starts_with(&format!("{}__", dir_safe_repo_name(&info.repo))) {
+                    let version_dir = entry_path.join(&info.version);
+                    if version_dir.exists() {
+                        tracing::info!(
+                            "Removing architecture-specific directory: {}",
+                            version_dir.display()
+                        );
+                        fs::remove_dir_all(&version_dir)?;
+                    }
+                }
+            }
+        }
+    }
+
+    Ok(())
+}
+
+/// Pull a tool back out of trash into the active config, by its original config key or repo name.
+pub fn restore_tool(config: &mut ToolerConfig, tool_query: &str) -> Result<()> {
+    let mut trash_store = load_trash()?;
+
+    let timestamp = trash_store
+        .entries
+        .iter()
+        .filter(|(_, entry)| {
+            entry.key == tool_query || entry.info.repo.to_lowercase() == tool_query.to_lowercase()
+        })
+        .max_by_key(|(_, entry)| entry.removed_at.clone())
+        .map(|(timestamp, _)| timestamp.clone())
+        .ok_or_else(|| anyhow!("'{}' not found in trash", tool_query))?;
+
+    if let Some(entry) = trash_store.entries.remove(&timestamp) {
+        config.tools.insert(entry.key.clone(), entry.info);
+        save_trash(&trash_store)?;
+        save_tool_configs(config)?;
+        tracing::info!("Restored '{}' from trash", entry.key);
+    }
+
+    Ok(())
+}
+
+/// Permanently empty the trash, deleting any install directories still held for its entries.
+/// Returns the number of entries purged.
+pub fn purge_trash(settings: &ToolerSettings) -> Result<usize> {
+    let mut trash_store = load_trash()?;
+    let count = trash_store.entries.len();
+
+    for entry in trash_store.entries.values() {
+        remove_tool_install_dirs(&entry.info, settings)?;
+    }
+
+    trash_store.entries.clear();
+    save_trash(&trash_store)?;
+    tracing::info!("Purged {} tool(s) from trash", count);
+    Ok(count)
+}
+
+/// Size and entry count of the download/extract scratch directory (see
+/// [`get_tooler_cache_dir`]). Normal runs clean up their own `TempDir` on exit; a non-zero
+/// result here means something crashed or was killed mid-install.
+pub struct CacheInfo {
+    pub entries: usize,
+    pub total_bytes: u64,
+}
+
+pub fn cache_info() -> Result<CacheInfo> {
+    let cache_dir = get_tooler_cache_dir()?;
+    let mut entries = 0;
+    let mut total_bytes = 0;
+
+    for entry in fs::read_dir(&cache_dir)?.flatten() {
+        entries += 1;
+        total_bytes += dir_size(&entry.path())?;
+    }
+
+    Ok(CacheInfo { entries, total_bytes })
+}
+
+fn dir_size(path: &Path) -> Result<u64> {
+    let metadata = fs::symlink_metadata(path)?;
+    if !metadata.is_dir() {
+        return Ok(metadata.len());
+    }
+
+    let mut total = 0;
+    for entry in fs::read_dir(path)?.flatten() {
+        total += dir_size(&entry.path())?;
+    }
+    Ok(total)
+}
+
+/// Delete every leftover entry in the download/extract scratch directory. Returns the number
+/// of entries removed.
+pub fn clear_cache() -> Result<usize> {
+    let cache_dir = get_tooler_cache_dir()?;
+    let mut removed = 0;
+
+    for entry in fs::read_dir(&cache_dir)?.flatten() {
+        let path = entry.path();
+        if path.is_dir() {
+            fs::remove_dir_all(&path)?;
+        } else {
+            fs::remove_file(&path)?;
+        }
+        removed += 1;
+    }
+
+    tracing::info!("Cleared {} entr(y/ies) from the cache directory", removed);
+    Ok(removed)
+}
+
+/// An installed tool whose latest eligible release is newer than what's installed.
+#[derive(Debug, Clone)]
+pub struct OutdatedTool {
+    pub tool_name: String,
+    pub repo: String,
+    pub current: String,
+    pub available: String,
+}
+
+/// Truncate a version string down to its `major.minor` prefix.
+fn major_minor(version: &str) -> String {
+    version
+        .trim_start_matches('v')
+        .splitn(3, '.')
+        .take(2)
+        .collect::<Vec<_>>()
+        .join(".")
+}
+
+/// Check installed tools against the latest release on their forge and report which are behind.
+///
+/// By default a pinned tool is only reported when the latest release falls within its current
+/// major.minor line, so a `1.5` install never suggests jumping to `1.6`; an unpinned tool is
+/// reported whenever the latest release differs. Pass `latest` to compare against the absolute
+/// newest release regardless of that constraint.
+pub async fn outdated(config: &ToolerConfig, latest: bool) -> Result<Vec<OutdatedTool>> {
+    let mut results = Vec::new();
+    let mut seen_repos = std::collections::HashSet::new();
+
+    for info in config.tools.values() {
+        if !seen_repos.insert(info.repo.clone()) {
+            continue;
+        }
+
+        // Dispatch by forge the same way `install_or_update_tool` does, so a `gitlab:`/`gitea:`
+        // repo doesn't get sent straight to the GitHub API.
+        let identifier = match ToolIdentifier::parse(&info.repo) {
+            Ok(identifier) => identifier,
+            Err(e) => {
+                tracing::warn!("Could not parse tool identifier for {}: {}", info.repo, e);
+                continue;
+            }
+        };
+        let release_result =
+            fetch_release_info(&identifier, None, false, config.settings.github_token.as_deref()).await;
+        let release = match release_result {
+            Ok(release) => release,
+            Err(e) => {
+                tracing::warn!("Could not check {} for updates: {}", info.repo, e);
+                continue;
+            }
+        };
+
+        let available = release.tag_name.trim_start_matches('v').to_string();
+        if available == info.version {
+            continue;
+        }
+
+        let within_pin = latest || !info.pinned || major_minor(&available) == major_minor(&info.version);
+
+        if within_pin {
+            results.push(OutdatedTool {
+                tool_name: info.tool_name.clone(),
+                repo: info.repo.clone(),
+                current: info.version.clone(),
+                available,
+            });
+        }
+    }
+
+    Ok(results)
+}
+
+/// Drive `install_or_update_tool` for every out-of-date tool matched by `filter` (or all of
+/// them when `filter` is `None`), returning `(name, from, to)` for each successful upgrade.
+/// Candidates are found from the caller's `config` snapshot, but each upgrade itself reloads
+/// fresh config under an exclusive lock immediately before installing (the same per-tool
+/// reload-under-lock pattern `Update`'s "all" loop uses), so a concurrent `tooler` process's
+/// changes can't be clobbered between one upgrade and the next.
+pub async fn upgrade(
+    config: &ToolerConfig,
+    filter: Option<&str>,
+    latest: bool,
+    no_rollback: bool,
+) -> Result<Vec<(String, String, String)>> {
+    let candidates = outdated(config, latest).await?;
+    let mut summary = Vec::new();
+
+    for tool in candidates {
+        if let Some(filter) = filter {
+            if tool.repo != filter && tool.tool_name != filter {
+                continue;
+            }
+        }
+
+        let tool_name = tool.tool_name.clone();
+        let repo = tool.repo.clone();
+        let available = tool.available.clone();
+        let result = crate::config::with_config_locked_async(move |locked_config| async move {
+            install_or_update_tool(
+                locked_config,
+                &tool_name,
+                &repo,
+                Some(&available),
+                true,
+                None,
+                false,
+                false,
+                false,
+                false,
+                no_rollback,
+            )
+            .await
+        })
+        .await;
+
+        match result {
+            Ok(_) => summary.push((tool.tool_name.clone(), tool.current.clone(), tool.available.clone())),
+            Err(e) => tracing::warn!("Failed to upgrade {}: {}", tool.repo, e),
+        }
+    }
+
+    Ok(summary)
+}
+
+/// Probe every configured tool's executable and stamp its resolved health state, persisting
+/// the result alongside the rest of the config. Returns `(tool_name, health)` for each tool,
+/// so callers can report what changed without reloading.
+pub fn check_tool_health(config: &mut ToolerConfig) -> Result<Vec<(String, ToolHealth)>> {
+    let mut results = Vec::new();
+    let mut toolstate_entries = Vec::new();
+    let checked_at = Utc::now().to_rfc3339();
+
+    for (key, info) in config.tools.iter_mut() {
+        let path = Path::new(&info.executable_path);
+        let state = if !path.exists() {
+            ToolHealth::Broken
+        } else if probe_version(path).is_some() {
+            ToolHealth::Working
+        } else {
+            ToolHealth::RunFail
+        };
+
+        info.health = Some(ToolHealthCheck {
+            state,
+            checked_at: checked_at.clone(),
+        });
+        results.push((info.tool_name.clone(), state));
+        toolstate_entries.push((key.clone(), state, checked_at.clone()));
+    }
+
+    save_tool_configs(config)?;
+    write_toolstate(&config.settings, &toolstate_entries)?;
+    Ok(results)
+}
+
+/// Merge `entries` (config key, health, checked-at) into the JSON snapshot at
+/// `settings.save_toolstate_path`, preserving entries for tools not touched by this run. A
+/// no-op when the setting is unset. Writes to a sibling `.tmp` file and renames it into place
+/// so a concurrent `tooler` invocation can't observe (or produce) a half-written file.
+fn write_toolstate(settings: &ToolerSettings, entries: &[(String, ToolHealth, String)]) -> Result<()> {
+    let Some(path) = settings.save_toolstate_path.as_ref() else {
+        return Ok(());
+    };
+    let path = Path::new(path);
+
+    let mut snapshot: serde_json::Map<String, serde_json::Value> = if path.exists() {
+        serde_json::from_str(&fs::read_to_string(path)?).unwrap_or_default()
+    } else {
+        serde_json::Map::new()
+    };
+
+    for (key, health, checked_at) in entries {
+        snapshot.insert(
+            key.clone(),
+            serde_json::json!({ "health": health, "checked_at": checked_at }),
+        );
+    }
+
+    let tmp_path = path.with_file_name(format!(
+        "{}.tmp",
+        path.file_name().unwrap_or_default().to_string_lossy()
+    ));
+    fs::write(&tmp_path, serde_json::to_string_pretty(&snapshot)?)?;
+    fs::rename(&tmp_path, path)?;
+    Ok(())
+}
+
+/// One entry of a `tooler doctor` report: a tool's config key (`owner/repo@version`) plus its
+/// freshly-probed health state and the timestamp it was checked at.
+#[derive(Debug, Clone)]
+pub struct DoctorEntry {
+    pub key: String,
+    pub health: ToolHealth,
+    pub checked_at: String,
+}
+
+/// Probe every configured tool (via [`check_tool_health`]) and return the result as a
+/// `doctor`-style report keyed by config key, mirroring rustc's toolstate tracking.
+pub fn doctor_report(config: &mut ToolerConfig) -> Result<Vec<DoctorEntry>> {
+    check_tool_health(config)?;
+
+    let mut entries: Vec<DoctorEntry> = config
+        .tools
+        .iter()
+        .filter_map(|(key, info)| {
+            info.health.as_ref().map(|h| DoctorEntry {
+                key: key.clone(),
+                health: h.state,
+                checked_at: h.checked_at.clone(),
+            })
+        })
+        .collect();
+    entries.sort_by(|a, b| a.key.cmp(&b.key));
+    Ok(entries)
+}
+
+/// How a config key's health changed between two toolstate snapshots.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ToolstateChange {
+    Regressed { from: ToolHealth, to: ToolHealth },
+    Recovered { from: ToolHealth, to: ToolHealth },
+    Appeared { health: ToolHealth },
+    Disappeared { health: ToolHealth },
+}
+
+#[derive(Debug, Clone)]
+pub struct ToolstateDiffEntry {
+    pub key: String,
+    pub change: ToolstateChange,
+}
+
+/// Compare a previously-saved toolstate snapshot (as written by [`write_toolstate`]) against a
+/// freshly-probed `doctor` report, classifying each config key as regressed, recovered, newly
+/// appeared, or disappeared since the snapshot was taken. Powers `tooler doctor --diff <old.json>`.
+pub fn diff_toolstate(old_path: &Path, current: &[DoctorEntry]) -> Result<Vec<ToolstateDiffEntry>> {
+    let content = fs::read_to_string(old_path)
+        .with_context(|| format!("failed to read toolstate snapshot at {}", old_path.display()))?;
+    let old: serde_json::Map<String, serde_json::Value> = serde_json::from_str(&content)
+        .with_context(|| format!("{} is not a valid toolstate snapshot", old_path.display()))?;
+
+    let old_health = |key: &str| -> Option<ToolHealth> {
+        old.get(key)
+            .and_then(|v| v.get("health"))
+            .and_then(|h| serde_json::from_value(h.clone()).ok())
+    };
+
+    let mut diffs = Vec::new();
+    let current_keys: std::collections::HashSet<&str> =
+        current.iter().map(|e| e.key.as_str()).collect();
+
+    for entry in current {
+        match old_health(&entry.key) {
+            Some(prev) if prev != entry.health => {
+                let change = if entry.health > prev {
+                    ToolstateChange::Recovered { from: prev, to: entry.health }
+                } else {
+                    ToolstateChange::Regressed { from: prev, to: entry.health }
+                };
+                diffs.push(ToolstateDiffEntry { key: entry.key.clone(), change });
+            }
+            Some(_) => {}
+            None => diffs.push(ToolstateDiffEntry {
+                key: entry.key.clone(),
+                change: ToolstateChange::Appeared { health: entry.health },
+            }),
+        }
+    }
+
+    for key in old.keys() {
+        if !current_keys.contains(key.as_str()) {
+            if let Some(health) = old_health(key) {
+                diffs.push(ToolstateDiffEntry {
+                    key: key.clone(),
+                    change: ToolstateChange::Disappeared { health },
+                });
+            }
+        }
+    }
+
+    diffs.sort_by(|a, b| a.key.cmp(&b.key));
+    Ok(diffs)
+}
+
+/// Remove every tool whose `executable_path` no longer exists on disk right now, regardless of
+/// what (if anything) its last `check` recorded. Catches an install directory deleted outside
+/// of `tooler` (a manual `rm`, a wiped data dir) without requiring `check` to have run first.
+/// Returns the config keys removed.
+pub fn prune_dangling_tools(config: &mut ToolerConfig) -> Result<Vec<String>> {
+    let dangling_keys: Vec<String> = config
+        .tools
+        .iter()
+        .filter(|(_, info)| !Path::new(&info.executable_path).exists())
+        .map(|(key, _)| key.clone())
+        .collect();
+
+    let mut removed = Vec::new();
+    for key in dangling_keys {
+        match remove_tool(config, &key, false) {
+            Ok(_) => removed.push(key),
+            Err(e) => tracing::warn!("Failed to prune dangling tool '{}': {}", key, e),
+        }
+    }
+
+    Ok(removed)
+}
+
+/// Remove every tool currently recorded as `Broken` or `RunFail` by the last `check`.
+/// Returns the config keys removed.
+pub fn remove_broken_tools(config: &mut ToolerConfig, trash: bool) -> Result<Vec<String>> {
+    let broken_keys: Vec<String> = config
+        .tools
+        .iter()
+        .filter(|(_, info)| {
+            matches!(
+                info.health.as_ref().map(|h| h.state),
+                Some(ToolHealth::Broken) | Some(ToolHealth::RunFail)
+            )
+        })
+        .map(|(key, _)| key.clone())
+        .collect();
+
+    let mut removed = Vec::new();
+    for key in broken_keys {
+        match remove_tool(config, &key, trash) {
+            Ok(_) => removed.push(key),
+            Err(e) => tracing::warn!("Failed to remove broken tool '{}': {}", key, e),
+        }
+    }
+
+    Ok(removed)
+}
@@ -0,0 +1,9 @@
+//! Library target exposing just enough of `tooler`'s types for out-of-tree test support.
+//! The CLI itself is built from `src/main.rs`, which declares its own copy of these modules;
+//! this target exists so integration tests (and downstream packagers writing their own
+//! scenario tests) can depend on `tooler::testkit` without linking the binary.
+
+pub mod types;
+
+#[cfg(feature = "testkit")]
+pub mod testkit;
@@ -10,22 +10,84 @@ use anyhow::{anyhow, Result};
 use chrono::{DateTime, Utc};
 use clap::Parser;
 use cli::{Cli, Commands, ConfigAction};
-use config::{load_tool_configs, normalize_key, save_tool_configs};
-use install::{find_tool_executable, install_or_update_tool, remove_tool};
+use config::{find_project_version, load_tool_configs, normalize_key, save_tool_configs};
+use install::{
+    cache_info, check_tool_health, clear_cache, doctor_report, find_global_tool_executable,
+    find_tool_executable, install_or_update_tool, outdated, prune_dangling_tools, purge_trash,
+    remove_broken_tools, remove_tool, restore_tool, upgrade, RemovalOutcome,
+};
 use tool_id::ToolIdentifier;
 use types::ToolerSettings;
 use std::env;
 use std::fs;
+use std::io::IsTerminal;
 use std::path::Path;
 use std::process::Command;
 
+/// Result of checking/updating a single tool via `tooler update`, rendered as one line of the
+/// summary table `print_update_report` prints at the end.
+enum UpdateOutcome {
+    Updated { from: String, to: String },
+    Unchanged,
+    Failed(String),
+}
+
+/// Whether to emit ANSI color codes: only when stdout is an actual terminal, following the
+/// usual "don't paint a log file or a pipe" convention.
+fn color_enabled() -> bool {
+    std::io::stdout().is_terminal()
+}
+
+fn colorize(text: &str, ansi_code: &str) -> String {
+    if color_enabled() {
+        format!("\x1b[{}m{}\x1b[0m", ansi_code, text)
+    } else {
+        text.to_string()
+    }
+}
+
+/// Print a rustup-`show_channel_update`-style summary of an `update all`/single-tool update run:
+/// green "updated vX -> vY", plain "unchanged", red "error: <reason>" per tool, followed by a
+/// total count. Suppressed entirely under `--quiet`.
+fn print_update_report(results: &[(String, UpdateOutcome)], quiet: bool) {
+    if quiet {
+        return;
+    }
+    if results.is_empty() {
+        println!("No applicable tools to update.");
+        return;
+    }
+
+    println!("--- Update Results ---");
+    let (mut updated, mut unchanged, mut failed) = (0, 0, 0);
+    for (repo, outcome) in results {
+        match outcome {
+            UpdateOutcome::Updated { from, to } => {
+                updated += 1;
+                println!("  {}: {}", repo, colorize(&format!("updated {} -> {}", from, to), "32"));
+            }
+            UpdateOutcome::Unchanged => {
+                unchanged += 1;
+                println!("  {}: unchanged", repo);
+            }
+            UpdateOutcome::Failed(reason) => {
+                failed += 1;
+                println!("  {}: {}", repo, colorize(&format!("error: {}", reason), "31"));
+            }
+        }
+    }
+    println!("----------------------");
+    println!("{} updated, {} unchanged, {} failed.", updated, unchanged, failed);
+}
+
 #[tokio::main]
 async fn main() -> Result<()> {
     let cli = Cli::parse();
     
     // Setup logging
     setup_logging(&cli)?;
-    
+    download::set_quiet(cli.quiet);
+
     // Load configuration
     let mut config = load_tool_configs()?;
     
@@ -35,46 +97,343 @@ async fn main() -> Result<()> {
             return Ok(());
         }
         
-        Commands::List => {
-            list_installed_tools(&config);
+        Commands::List { broken } => {
+            list_installed_tools(&config, broken);
         }
-        
-        Commands::Remove { tool_id } => {
-            let tool_identifier = ToolIdentifier::parse(&tool_id)
+
+        Commands::Check => {
+            let results = check_tool_health(&mut config)?;
+            for (tool_name, state) in &results {
+                println!("  {}: {:?}", tool_name, state);
+            }
+            let broken = results
+                .iter()
+                .filter(|(_, state)| *state != types::ToolHealth::Working)
+                .count();
+            println!("Checked {} tool(s), {} broken/missing.", results.len(), broken);
+        }
+
+        Commands::Doctor { format, diff } => {
+            let report = doctor_report(&mut config)?;
+            let any_broken = report.iter().any(|entry| entry.health == types::ToolHealth::Broken);
+
+            if let Some(old_path) = diff {
+                let diffs = install::diff_toolstate(Path::new(&old_path), &report)?;
+                if diffs.is_empty() {
+                    println!("No toolstate changes since {}.", old_path);
+                } else {
+                    for entry in &diffs {
+                        match entry.change {
+                            install::ToolstateChange::Regressed { from, to } => {
+                                println!("  {}: {}", entry.key, colorize(&format!("regressed {:?} -> {:?}", from, to), "31"));
+                            }
+                            install::ToolstateChange::Recovered { from, to } => {
+                                println!("  {}: {}", entry.key, colorize(&format!("recovered {:?} -> {:?}", from, to), "32"));
+                            }
+                            install::ToolstateChange::Appeared { health } => {
+                                println!("  {}: appeared ({:?})", entry.key, health);
+                            }
+                            install::ToolstateChange::Disappeared { health } => {
+                                println!("  {}: disappeared (was {:?})", entry.key, health);
+                            }
+                        }
+                    }
+                    println!("{} change(s) since {}.", diffs.len(), old_path);
+                }
+                if any_broken {
+                    std::process::exit(1);
+                }
+                return Ok(());
+            }
+
+            match format.as_str() {
+                "json" => {
+                    let map: std::collections::BTreeMap<&str, serde_json::Value> = report
+                        .iter()
+                        .map(|entry| {
+                            (
+                                entry.key.as_str(),
+                                serde_json::json!({ "health": entry.health, "checked_at": entry.checked_at }),
+                            )
+                        })
+                        .collect();
+                    println!("{}", serde_json::to_string_pretty(&map)?);
+                }
+                "yaml" => {
+                    let map: std::collections::BTreeMap<&str, serde_yaml::Value> = report
+                        .iter()
+                        .map(|entry| {
+                            let entry_map = serde_json::json!({ "health": entry.health, "checked_at": entry.checked_at });
+                            (entry.key.as_str(), serde_yaml::to_value(entry_map).unwrap())
+                        })
+                        .collect();
+                    println!("{}", serde_yaml::to_string(&map)?);
+                }
+                _ => {
+                    for entry in &report {
+                        println!("  {}: {:?} (checked {})", entry.key, entry.health, entry.checked_at);
+                    }
+                    println!("Checked {} tool(s).", report.len());
+                }
+            }
+
+            if any_broken {
+                std::process::exit(1);
+            }
+        }
+
+        Commands::Remove { tool_id, trash, broken } => {
+            if broken {
+                // Reloads fresh under an exclusive lock right before mutating/saving, rather
+                // than acting on the `config` snapshot loaded at start-of-`main`, so a
+                // concurrent `tooler` process's changes can't be clobbered.
+                let removed = config::with_config_locked(|locked_config| {
+                    remove_broken_tools(locked_config, trash)
+                })?;
+                config = load_tool_configs()?;
+                if removed.is_empty() {
+                    println!("No broken or missing tools to remove.");
+                } else {
+                    println!("Removed {} broken/missing tool(s): {}", removed.len(), removed.join(", "));
+                }
+                prune_stale_shims(&config, &config.settings.shim_dir)?;
+            } else {
+                let tool_id = tool_id
+                    .ok_or_else(|| anyhow!("A tool id is required unless --broken is given"))?;
+                let tool_identifier = ToolIdentifier::parse_with_default_forge(&tool_id, &config.settings.default_forge)
+                    .map_err(|e| anyhow!("Invalid tool identifier: {}", e))?;
+                let tool_name = tool_identifier.tool_name();
+                let config_key = tool_identifier.config_key();
+                let outcome = config::with_config_locked(|locked_config| {
+                    remove_tool(locked_config, &config_key, trash)
+                })?;
+                config = load_tool_configs()?;
+                match outcome {
+                    RemovalOutcome::NotFound => {
+                        return Err(anyhow!("Tool '{}' not found", tool_id));
+                    }
+                    RemovalOutcome::Removed => {}
+                }
+                // Only drop the shim if no other version of this tool is still installed.
+                if !config.tools.values().any(|info| info.tool_name == tool_name) {
+                    remove_tool_symlink(&config.settings.shim_dir, &tool_name)?;
+                }
+            }
+        }
+
+        Commands::Shim { tool_id, all, prune } => {
+            if prune {
+                let removed = prune_stale_shims(&config, &config.settings.shim_dir)?;
+                if removed.is_empty() {
+                    println!("No stale shims to remove.");
+                } else {
+                    println!("Removed {} stale shim(s): {}", removed.len(), removed.join(", "));
+                }
+            } else if all {
+                let tool_names: std::collections::BTreeSet<String> = config
+                    .tools
+                    .values()
+                    .map(|info| info.tool_name.clone())
+                    .collect();
+                create_shim_script(&config.settings.shim_dir)?;
+                for tool_name in &tool_names {
+                    create_tool_symlink(&config.settings.shim_dir, tool_name)?;
+                }
+                println!("Generated {} shim(s) in {}.", tool_names.len(), config.settings.shim_dir);
+            } else {
+                let tool_id = tool_id
+                    .ok_or_else(|| anyhow!("A tool id is required unless --all or --prune is given"))?;
+                let tool_identifier = ToolIdentifier::parse_with_default_forge(&tool_id, &config.settings.default_forge)
+                    .map_err(|e| anyhow!("Invalid tool identifier: {}", e))?;
+                create_shim_script(&config.settings.shim_dir)?;
+                create_tool_symlink(&config.settings.shim_dir, &tool_identifier.tool_name())?;
+                println!("Generated shim for '{}' in {}.", tool_id, config.settings.shim_dir);
+            }
+        }
+
+        Commands::Restore { tool_id } => {
+            // Reloads fresh under an exclusive lock right before mutating/saving, rather than
+            // acting on the `config` snapshot loaded at start-of-`main`, so a concurrent
+            // `tooler` process's changes can't be clobbered.
+            config::with_config_locked(|locked_config| restore_tool(locked_config, &tool_id))?;
+            println!("Restored '{}' from trash.", tool_id);
+        }
+
+        Commands::Purge => {
+            let count = purge_trash(&config.settings)?;
+            println!("Purged {} tool(s) from trash.", count);
+        }
+
+        Commands::Prune => {
+            // Reloads fresh under an exclusive lock right before mutating/saving, rather than
+            // acting on the `config` snapshot loaded at start-of-`main`, so a concurrent
+            // `tooler` process's changes can't be clobbered.
+            let removed = config::with_config_locked(prune_dangling_tools)?;
+            config = load_tool_configs()?;
+            if removed.is_empty() {
+                println!("No dangling tools to prune.");
+            } else {
+                println!("Pruned {} dangling tool(s): {}", removed.len(), removed.join(", "));
+            }
+            let stale_shims = prune_stale_shims(&config, &config.settings.shim_dir)?;
+            if !stale_shims.is_empty() {
+                println!("Removed {} dangling shim(s): {}", stale_shims.len(), stale_shims.join(", "));
+            }
+        }
+
+        Commands::Local { tool_id } => {
+            let tool_identifier = ToolIdentifier::parse_with_default_forge(&tool_id, &config.settings.default_forge)
                 .map_err(|e| anyhow!("Invalid tool identifier: {}", e))?;
-            remove_tool(&mut config, &tool_identifier.config_key())?;
+            let version = tool_identifier
+                .version
+                .clone()
+                .filter(|v| v != "default")
+                .ok_or_else(|| anyhow!("'{}' needs an explicit version (e.g. 'owner/repo@v1.2.3') to pin for this project", tool_id))?;
+            let path = config::write_project_version(&tool_identifier.tool_name(), &version)?;
+            println!("Pinned {} to {} in {}.", tool_identifier.tool_name(), version, path.display());
+        }
+
+        Commands::Cache { action } => match action {
+            cli::CacheAction::Info => {
+                let info = cache_info()?;
+                println!(
+                    "{} entr(y/ies), {:.2} MB in the download/extract scratch directory.",
+                    info.entries,
+                    info.total_bytes as f64 / (1024.0 * 1024.0)
+                );
+            }
+            cli::CacheAction::Clear => {
+                let removed = clear_cache()?;
+                println!("Cleared {} entr(y/ies) from the scratch directory.", removed);
+            }
+        },
+
+        Commands::ClearCache => {
+            let removed = clear_cache()?;
+            println!("Cleared {} entr(y/ies) from the scratch directory.", removed);
+        }
+
+        Commands::RemapShims => {
+            let tool_names: std::collections::BTreeSet<String> = config
+                .tools
+                .values()
+                .filter(|info| !info.pinned)
+                .map(|info| info.tool_name.clone())
+                .collect();
+            create_shim_script(&config.settings.shim_dir)?;
+            for tool_name in &tool_names {
+                create_tool_symlink(&config.settings.shim_dir, tool_name)?;
+            }
+            println!("Generated {} shim(s) in {}.", tool_names.len(), config.settings.shim_dir);
+        }
+
+        Commands::Outdated { latest } => {
+            let stale = outdated(&config, latest).await?;
+            if stale.is_empty() {
+                println!("All tools are up to date.");
+            } else {
+                println!("--- Outdated Tools ---");
+                for tool in stale {
+                    println!("  {}: {} -> {}", tool.tool_name, tool.current, tool.available);
+                }
+            }
+        }
+
+        Commands::Upgrade { tool_id, latest, no_rollback } => {
+            let upgraded = upgrade(&config, tool_id.as_deref(), latest, no_rollback).await?;
+            if upgraded.is_empty() {
+                println!("Nothing to upgrade.");
+            } else {
+                println!("--- Upgraded Tools ---");
+                for (name, from, to) in upgraded {
+                    println!("  {}: {} -> {}", name, from, to);
+                }
+            }
         }
         
-        Commands::Update { tool_id } => {
+        Commands::Update { tool_id, require_checksum, insecure_skip_verify, no_rollback } => {
             if let Some(tool_id) = tool_id {
                 if tool_id == "all" {
                     tracing::info!("Updating all applicable tools...");
-                    let mut updated_count = 0;
                     let keys_to_update: Vec<String> = config.tools
-                        .keys()
-                        .filter(|k| !k.contains(':')) // Only non-version-pinned tools
-                        .cloned()
+                        .iter()
+                        .filter(|(_, info)| !info.pinned) // Only non-version-pinned tools
+                        .map(|(k, _)| k.clone())
                         .collect();
-                    
+
+                    // Every tool's download bar renders under this one shared area instead of
+                    // each tool's bar clobbering the previous tool's finished line.
+                    download::set_multi_progress(Some(indicatif::MultiProgress::new()));
+
+                    let mut results: Vec<(String, UpdateOutcome)> = Vec::new();
                     for key in keys_to_update {
                         if let Some(info) = config.tools.get(&key).cloned() {
-                            match install_or_update_tool(&mut config, &info.tool_name, &info.repo, Some("latest"), true, None).await {
-                                Ok(_) => updated_count += 1,
-                                Err(e) => tracing::warn!("Failed to update {}: {}", info.repo, e),
-                            }
+                            let previous_version = info.version.clone();
+                            let tool_name = info.tool_name.clone();
+                            let repo = info.repo.clone();
+                            // Reloaded fresh under an exclusive lock per tool, not once for the
+                            // whole batch, so a concurrent `tooler run` only blocks for one
+                            // tool's read-modify-save cycle instead of the full `update all`
+                            // wall-clock time, and the install sees the latest on-disk state
+                            // instead of this loop's stale top-level snapshot.
+                            let outcome = config::with_config_locked_async(move |locked_config| async move {
+                                match install_or_update_tool(locked_config, &tool_name, &repo, Some("latest"), true, None, require_checksum, false, false, insecure_skip_verify, no_rollback).await {
+                                    Ok(path) => {
+                                        let new_version = locked_config.tools.values()
+                                            .find(|t| t.executable_path == path.to_string_lossy())
+                                            .map(|t| t.version.clone())
+                                            .unwrap_or_else(|| previous_version.clone());
+                                        Ok(if new_version == previous_version {
+                                            UpdateOutcome::Unchanged
+                                        } else {
+                                            UpdateOutcome::Updated { from: previous_version.clone(), to: new_version }
+                                        })
+                                    }
+                                    Err(e) => Ok(UpdateOutcome::Failed(e.to_string())),
+                                }
+                            })
+                            .await
+                            .unwrap_or_else(|e| UpdateOutcome::Failed(e.to_string()));
+                            config = load_tool_configs()?;
+                            results.push((info.repo.clone(), outcome));
                         }
                     }
-                    tracing::info!("Update process finished. {} tool(s) were checked/updated", updated_count);
+                    download::set_multi_progress(None);
+                    print_update_report(&results, cli.quiet);
                 } else {
-                    let tool_identifier = ToolIdentifier::parse(&tool_id)
+                    let tool_identifier = ToolIdentifier::parse_with_default_forge(&tool_id, &config.settings.default_forge)
                         .map_err(|e| anyhow!("Invalid tool identifier: {}", e))?;
+                    let previous_version = config.tools.values()
+                        .find(|t| t.repo == tool_identifier.full_repo())
+                        .map(|t| t.version.clone());
                     tracing::info!("Attempting to update {}...", tool_id);
-                    match install_or_update_tool(&mut config, &tool_identifier.tool_name(), &tool_identifier.full_repo(), Some("latest"), true, None).await {
-                        Ok(_) => tracing::info!("{} updated successfully", tool_id),
-                        Err(e) => {
-                            tracing::error!("Failed to update {}: {}", tool_id, e);
-                            std::process::exit(1);
+                    let tool_name = tool_identifier.tool_name();
+                    let full_repo = tool_identifier.full_repo();
+                    // Reloaded fresh under an exclusive lock for this tool's read-modify-save
+                    // cycle, rather than mutating the `config` snapshot loaded at start-of-`main`.
+                    let outcome = config::with_config_locked_async(move |locked_config| async move {
+                        match install_or_update_tool(locked_config, &tool_name, &full_repo, Some("latest"), true, None, require_checksum, false, false, insecure_skip_verify, no_rollback).await {
+                            Ok(path) => {
+                                let new_version = locked_config.tools.values()
+                                    .find(|t| t.executable_path == path.to_string_lossy())
+                                    .map(|t| t.version.clone())
+                                    .unwrap_or_default();
+                                Ok(match &previous_version {
+                                    Some(prev) if *prev == new_version => UpdateOutcome::Unchanged,
+                                    Some(prev) => UpdateOutcome::Updated { from: prev.clone(), to: new_version },
+                                    None => UpdateOutcome::Updated { from: "(none)".to_string(), to: new_version },
+                                })
+                            }
+                            Err(e) => Ok(UpdateOutcome::Failed(e.to_string())),
                         }
+                    })
+                    .await
+                    .unwrap_or_else(|e| UpdateOutcome::Failed(e.to_string()));
+                    config = load_tool_configs()?;
+                    let failed = matches!(outcome, UpdateOutcome::Failed(_));
+                    print_update_report(&[(tool_id.clone(), outcome)], cli.quiet);
+                    if failed {
+                        std::process::exit(1);
                     }
                 }
             } else {
@@ -91,15 +450,32 @@ async fn main() -> Result<()> {
                             "update_check_days" => config.settings.update_check_days.to_string(),
                             "auto_shim" => config.settings.auto_shim.to_string(),
                             "shim_dir" => config.settings.shim_dir.clone(),
+                            "prefer_global" => config.settings.prefer_global.to_string(),
+                            "verify_checksums" => config.settings.verify_checksums.clone(),
+                            "default_forge" => config.settings.default_forge.clone(),
+                            "gpg_public_key_path" => config.settings.gpg_public_key_path.clone().unwrap_or_default(),
+                            "github_token" => redact_secret(config.settings.github_token.as_deref()),
+                            "no_system_cache" => config.settings.no_system_cache.to_string(),
+                            "save_toolstate_path" => config.settings.save_toolstate_path.clone().unwrap_or_default(),
                             _ => format!("Setting '{}' not found", key),
                         };
                         println!("{}", value);
                     } else {
                         println!("--- Tooler Settings ---");
+                        let gpg_public_key_path = config.settings.gpg_public_key_path.clone().unwrap_or_default();
+                        let github_token = redact_secret(config.settings.github_token.as_deref());
+                        let save_toolstate_path = config.settings.save_toolstate_path.clone().unwrap_or_default();
                         for (k, v) in &[
                             ("update_check_days", &config.settings.update_check_days.to_string()),
                             ("auto_shim", &config.settings.auto_shim.to_string()),
                             ("shim_dir", &config.settings.shim_dir),
+                            ("prefer_global", &config.settings.prefer_global.to_string()),
+                            ("verify_checksums", &config.settings.verify_checksums),
+                            ("default_forge", &config.settings.default_forge),
+                            ("gpg_public_key_path", &gpg_public_key_path),
+                            ("github_token", &github_token),
+                            ("no_system_cache", &config.settings.no_system_cache.to_string()),
+                            ("save_toolstate_path", &save_toolstate_path),
                         ] {
                             println!("  {}: {}", k, v);
                         }
@@ -108,11 +484,16 @@ async fn main() -> Result<()> {
                 ConfigAction::Set { key_value } => {
                     if let Some((key, value_str)) = key_value.split_once('=') {
                         let key = normalize_key(key);
+                        // Reloads fresh under an exclusive lock right before mutating/saving
+                        // (rather than acting on the `config` snapshot loaded at start-of-`main`),
+                        // so a concurrent `tooler` process's changes can't be clobbered.
                         match key.as_str() {
                             "update_check_days" => {
                                 if let Ok(days) = value_str.parse::<i32>() {
-                                    config.settings.update_check_days = days;
-                                    save_tool_configs(&config)?;
+                                    config::with_config_locked(|locked_config| {
+                                        locked_config.settings.update_check_days = days;
+                                        Ok(())
+                                    })?;
                                     tracing::info!("Setting '{}' updated to '{}'", key, days);
                                 } else {
                                     tracing::error!("Invalid value for '{}'", key);
@@ -120,17 +501,82 @@ async fn main() -> Result<()> {
                             }
                             "auto_shim" => {
                                 let value = value_str.to_lowercase() == "true" || value_str == "1";
-                                config.settings.auto_shim = value;
-                                save_tool_configs(&config)?;
+                                config::with_config_locked(|locked_config| {
+                                    locked_config.settings.auto_shim = value;
+                                    Ok(())
+                                })?;
                                 tracing::info!("Setting '{}' updated to '{}'", key, value);
                             }
                             "shim_dir" => {
-                                config.settings.shim_dir = value_str.to_string();
-                                save_tool_configs(&config)?;
+                                config::with_config_locked(|locked_config| {
+                                    locked_config.settings.shim_dir = value_str.to_string();
+                                    Ok(())
+                                })?;
+                                tracing::info!("Setting '{}' updated to '{}'", key, value_str);
+                            }
+                            "prefer_global" => {
+                                let value = value_str.to_lowercase() == "true" || value_str == "1";
+                                config::with_config_locked(|locked_config| {
+                                    locked_config.settings.prefer_global = value;
+                                    Ok(())
+                                })?;
+                                tracing::info!("Setting '{}' updated to '{}'", key, value);
+                            }
+                            "verify_checksums" => {
+                                let value = value_str.to_lowercase();
+                                if matches!(value.as_str(), "true" | "warn" | "off") {
+                                    config::with_config_locked(|locked_config| {
+                                        locked_config.settings.verify_checksums = value;
+                                        Ok(())
+                                    })?;
+                                    tracing::info!("Setting '{}' updated to '{}'", key, value_str);
+                                } else {
+                                    tracing::error!("Invalid value for '{}'. Expected one of: true, warn, off", key);
+                                }
+                            }
+                            "default_forge" => {
+                                let value = value_str.to_lowercase();
+                                if matches!(value.as_str(), "github" | "gitlab" | "gitea" | "forgejo") {
+                                    config::with_config_locked(|locked_config| {
+                                        locked_config.settings.default_forge = value;
+                                        Ok(())
+                                    })?;
+                                    tracing::info!("Setting '{}' updated to '{}'", key, value_str);
+                                } else {
+                                    tracing::error!("Invalid value for '{}'. Expected one of: github, gitlab, gitea, forgejo", key);
+                                }
+                            }
+                            "gpg_public_key_path" => {
+                                config::with_config_locked(|locked_config| {
+                                    locked_config.settings.gpg_public_key_path = Some(value_str.to_string());
+                                    Ok(())
+                                })?;
+                                tracing::info!("Setting '{}' updated to '{}'", key, value_str);
+                            }
+                            "github_token" => {
+                                config::with_config_locked(|locked_config| {
+                                    locked_config.settings.github_token = Some(value_str.to_string());
+                                    Ok(())
+                                })?;
+                                tracing::info!("Setting '{}' updated to {}", key, redact_secret(Some(value_str)));
+                            }
+                            "no_system_cache" => {
+                                let value = value_str.to_lowercase() == "true" || value_str == "1";
+                                config::with_config_locked(|locked_config| {
+                                    locked_config.settings.no_system_cache = value;
+                                    Ok(())
+                                })?;
+                                tracing::info!("Setting '{}' updated to '{}'", key, value);
+                            }
+                            "save_toolstate_path" => {
+                                config::with_config_locked(|locked_config| {
+                                    locked_config.settings.save_toolstate_path = Some(value_str.to_string());
+                                    Ok(())
+                                })?;
                                 tracing::info!("Setting '{}' updated to '{}'", key, value_str);
                             }
                             _ => {
-                                tracing::error!("'{}' is not a valid configuration setting. Valid settings: update_check_days, auto_shim, shim_dir", key);
+                                tracing::error!("'{}' is not a valid configuration setting. Valid settings: update_check_days, auto_shim, shim_dir, prefer_global, verify_checksums, default_forge, gpg_public_key_path, github_token, no_system_cache, save_toolstate_path", key);
                             }
                         }
                     } else {
@@ -139,52 +585,136 @@ async fn main() -> Result<()> {
                 }
                 ConfigAction::Unset { key } => {
                     let key = normalize_key(&key);
+                    // Same reload-under-lock treatment as `Set` above.
                     match key.as_str() {
                         "update_check_days" => {
-                            config.settings.update_check_days = ToolerSettings::default().update_check_days;
-                            save_tool_configs(&config)?;
+                            config::with_config_locked(|locked_config| {
+                                locked_config.settings.update_check_days = ToolerSettings::default().update_check_days;
+                                Ok(())
+                            })?;
                             tracing::info!("Setting '{}' unset", key);
                         }
                         "auto_shim" => {
-                            config.settings.auto_shim = ToolerSettings::default().auto_shim;
-                            save_tool_configs(&config)?;
+                            config::with_config_locked(|locked_config| {
+                                locked_config.settings.auto_shim = ToolerSettings::default().auto_shim;
+                                Ok(())
+                            })?;
                             tracing::info!("Setting '{}' unset", key);
                         }
                         "shim_dir" => {
-                            config.settings.shim_dir = ToolerSettings::default().shim_dir;
-                            save_tool_configs(&config)?;
+                            config::with_config_locked(|locked_config| {
+                                locked_config.settings.shim_dir = ToolerSettings::default().shim_dir;
+                                Ok(())
+                            })?;
+                            tracing::info!("Setting '{}' unset", key);
+                        }
+                        "prefer_global" => {
+                            config::with_config_locked(|locked_config| {
+                                locked_config.settings.prefer_global = ToolerSettings::default().prefer_global;
+                                Ok(())
+                            })?;
+                            tracing::info!("Setting '{}' unset", key);
+                        }
+                        "verify_checksums" => {
+                            config::with_config_locked(|locked_config| {
+                                locked_config.settings.verify_checksums = ToolerSettings::default().verify_checksums;
+                                Ok(())
+                            })?;
+                            tracing::info!("Setting '{}' unset", key);
+                        }
+                        "default_forge" => {
+                            config::with_config_locked(|locked_config| {
+                                locked_config.settings.default_forge = ToolerSettings::default().default_forge;
+                                Ok(())
+                            })?;
+                            tracing::info!("Setting '{}' unset", key);
+                        }
+                        "gpg_public_key_path" => {
+                            config::with_config_locked(|locked_config| {
+                                locked_config.settings.gpg_public_key_path = ToolerSettings::default().gpg_public_key_path;
+                                Ok(())
+                            })?;
+                            tracing::info!("Setting '{}' unset", key);
+                        }
+                        "github_token" => {
+                            config::with_config_locked(|locked_config| {
+                                locked_config.settings.github_token = ToolerSettings::default().github_token;
+                                Ok(())
+                            })?;
+                            tracing::info!("Setting '{}' unset", key);
+                        }
+                        "no_system_cache" => {
+                            config::with_config_locked(|locked_config| {
+                                locked_config.settings.no_system_cache = ToolerSettings::default().no_system_cache;
+                                Ok(())
+                            })?;
+                            tracing::info!("Setting '{}' unset", key);
+                        }
+                        "save_toolstate_path" => {
+                            config::with_config_locked(|locked_config| {
+                                locked_config.settings.save_toolstate_path = ToolerSettings::default().save_toolstate_path;
+                                Ok(())
+                            })?;
                             tracing::info!("Setting '{}' unset", key);
                         }
                         _ => {
-                            tracing::error!("'{}' is not a valid configuration setting. Valid settings: update_check_days, auto_shim, shim_dir", key);
+                            tracing::error!("'{}' is not a valid configuration setting. Valid settings: update_check_days, auto_shim, shim_dir, prefer_global, verify_checksums, default_forge, gpg_public_key_path, github_token, no_system_cache, save_toolstate_path", key);
                         }
                     }
                 }
             }
         }
         
-        Commands::Run { tool_id, tool_args, asset } => {
-            let tool_identifier = ToolIdentifier::parse(&tool_id)
+        Commands::Run { tool_id, tool_args, asset, require_checksum, offline, include_prereleases, insecure_skip_verify } => {
+            let mut tool_identifier = ToolIdentifier::parse_with_default_forge(&tool_id, &config.settings.default_forge)
                 .map_err(|e| anyhow!("Invalid tool identifier: {}", e))?;
+
+            // An explicit `@version` in `tool_id` always wins. Otherwise, in priority order:
+            // `--use-version`, then the nearest `.tooler-versions` entry for this tool.
+            if tool_identifier.version.as_deref() == Some("default") {
+                if let Some(version) = &cli.use_version {
+                    tool_identifier.version = Some(version.clone());
+                } else if let Some(version) = find_project_version(&tool_identifier.tool_name()) {
+                    tool_identifier.version = Some(version);
+                }
+            }
+            let tool_id = tool_identifier.to_string();
             let version_req = tool_identifier.api_version();
-            
+
             // Check for updates if not a pinned version
-            if !tool_identifier.is_pinned() {
+            if !tool_identifier.is_pinned() && !offline {
                 check_for_updates(&mut config).await?;
             }
-            
-            let mut tool_info = find_tool_executable(&config, &tool_id);
-            
+
+            let mut tool_info = find_tool_executable(&config, &tool_id).cloned();
+
+            if tool_info.is_none() && config.settings.prefer_global {
+                // Only an exact pinned version constrains the global binary; an unpinned
+                // request (the common case) accepts whatever version is already on PATH.
+                let required_version = tool_identifier.is_pinned().then_some(version_req.as_str());
+                tool_info = find_global_tool_executable(&tool_identifier.tool_name(), required_version);
+            }
+
             // Install if not found or if asset override is used
             if tool_info.is_none() || asset.is_some() {
                 if tool_info.is_none() {
                     tracing::info!("Tool {} not found locally or is corrupted. Attempting to install...", tool_id);
                 }
-                
-                match install_or_update_tool(&mut config, &tool_identifier.tool_name(), &tool_identifier.full_repo(), Some(&version_req), false, asset.as_deref()).await {
+
+                let tool_name = tool_identifier.tool_name();
+                let full_repo = tool_identifier.full_repo();
+                // Reloads fresh under an exclusive lock right before installing (rather than
+                // mutating the `config` snapshot loaded at start-of-`main`), so a concurrent
+                // `tooler run <other-tool>` auto-install that already landed its own changes
+                // isn't clobbered by this process's stale in-memory state.
+                let install_result = config::with_config_locked_async(move |locked_config| async move {
+                    install_or_update_tool(locked_config, &tool_name, &full_repo, Some(&version_req), false, asset.as_deref(), require_checksum, offline, include_prereleases, insecure_skip_verify, false).await
+                })
+                .await;
+                match install_result {
                     Ok(_) => {
                         config = load_tool_configs()?; // Reload config
-                        tool_info = find_tool_executable(&config, &tool_id);
+                        tool_info = find_tool_executable(&config, &tool_id).cloned();
                     }
                     Err(e) => {
                         tracing::error!("Failed to install tool: {}", e);
@@ -203,14 +733,15 @@ async fn main() -> Result<()> {
                 // Update last accessed time
                 let key = tool_identifier.config_key();
                 let executable_path = info.executable_path.clone();
-                
-                // Update config in separate scope
-                {
-                    if let Some(tool_info) = config.tools.get_mut(&key) {
+
+                // Re-loads, mutates, and saves under an exclusive lock so a concurrent `tooler
+                // run` for a different tool can't clobber this update (or vice versa).
+                config::with_config_locked(|locked_config| {
+                    if let Some(tool_info) = locked_config.tools.get_mut(&key) {
                         tool_info.last_accessed = Utc::now().to_rfc3339();
-                        save_tool_configs(&config)?;
                     }
-                }
+                    Ok(())
+                })?;
                 
                 // Execute tool
                 let mut cmd = Command::new(&executable_path);
@@ -257,23 +788,51 @@ fn setup_logging(cli: &Cli) -> Result<()> {
     Ok(())
 }
 
-fn list_installed_tools(config: &types::ToolerConfig) {
+fn list_installed_tools(config: &types::ToolerConfig, broken_only: bool) {
     println!("--- Installed Tooler Tools ---");
     if config.tools.is_empty() {
         println!("  No tools installed yet.");
         return;
     }
-    
-    let mut tools: Vec<_> = config.tools.values().collect();
+
+    let mut tools: Vec<_> = config
+        .tools
+        .values()
+        .filter(|info| {
+            !broken_only
+                || matches!(
+                    info.health.as_ref().map(|h| h.state),
+                    Some(types::ToolHealth::Broken) | Some(types::ToolHealth::RunFail)
+                )
+        })
+        .collect();
     tools.sort_by_key(|t| &t.repo);
-    
+
+    if tools.is_empty() {
+        println!("  No tools match.");
+        println!("------------------------------");
+        return;
+    }
+
     for info in tools {
         println!("  - {} (v{}) [type: {}]", info.repo, info.version, info.install_type);
-        println!("    Path:    {}\n", info.executable_path);
+        println!("    Path:    {}", info.executable_path);
+        if let Some(health) = &info.health {
+            println!("    Health:  {:?} (checked {})", health.state, health.checked_at);
+        }
+        println!();
     }
     println!("------------------------------");
 }
 
+/// Summarize a secret setting for display without ever printing its value.
+fn redact_secret(value: Option<&str>) -> String {
+    match value {
+        Some(v) if !v.is_empty() => "(set)".to_string(),
+        _ => "(not set)".to_string(),
+    }
+}
+
 async fn check_for_updates(config: &mut types::ToolerConfig) -> Result<()> {
     if config.settings.update_check_days <= 0 {
         return Ok(());
@@ -284,9 +843,9 @@ async fn check_for_updates(config: &mut types::ToolerConfig) -> Result<()> {
     let mut updates_found = Vec::new();
     
     let keys_to_check: Vec<String> = config.tools
-        .keys()
-        .filter(|k| !k.contains(':')) // Only non-version-pinned tools
-        .cloned()
+        .iter()
+        .filter(|(_, info)| !info.pinned) // Only non-version-pinned tools
+        .map(|(k, _)| k.clone())
         .collect();
     
     for key in keys_to_check {
@@ -298,9 +857,22 @@ async fn check_for_updates(config: &mut types::ToolerConfig) -> Result<()> {
                 tracing::info!("Checking for update for {} (current: {}, last updated: {} days ago)", 
                     info.repo, info.version, days_since_update);
                 
-                if let Ok(release) = install::get_gh_release_info(&info.repo, Some("latest")).await {
+                let release_result = match ToolIdentifier::parse(&info.repo) {
+                    Ok(identifier) => {
+                        install::fetch_release_info(
+                            &identifier,
+                            Some("latest"),
+                            false,
+                            config.settings.github_token.as_deref(),
+                        )
+                        .await
+                    }
+                    Err(e) => Err(anyhow!("Could not parse tool identifier for {}: {}", info.repo, e)),
+                };
+
+                if let Ok(release) = release_result {
                     if release.tag_name != info.version {
-                        updates_found.push(format!("Tool {} ({}) has update: {} -> {} (last updated {} days ago)", 
+                        updates_found.push(format!("Tool {} ({}) has update: {} -> {} (last updated {} days ago)",
                             info.tool_name, info.repo, info.version, release.tag_name, days_since_update));
                     }
                     
@@ -372,6 +944,40 @@ fn create_shim_script(shim_dir: &str) -> Result<()> {
     Ok(())
 }
 
+/// Remove shims in `shim_dir` that no longer correspond to an installed tool, leaving
+/// `tooler-shim` itself and any entry whose name matches a currently installed tool untouched.
+fn prune_stale_shims(config: &types::ToolerConfig, shim_dir: &str) -> Result<Vec<String>> {
+    let shim_path = Path::new(shim_dir);
+    if !shim_path.exists() {
+        return Ok(Vec::new());
+    }
+
+    let installed_names: std::collections::BTreeSet<String> = config
+        .tools
+        .values()
+        .map(|info| info.tool_name.clone())
+        .collect();
+
+    let mut removed = Vec::new();
+    for entry in fs::read_dir(shim_path)? {
+        let entry = entry?;
+        let name = entry.file_name().to_string_lossy().to_string();
+        if name == "tooler-shim" || installed_names.contains(&name) {
+            continue;
+        }
+
+        let path = entry.path();
+        if path.is_dir() {
+            continue;
+        }
+        fs::remove_file(&path)?;
+        tracing::info!("Removed stale shim {}", path.display());
+        removed.push(name);
+    }
+    removed.sort();
+    Ok(removed)
+}
+
 fn create_tool_symlink(shim_dir: &str, tool_name: &str) -> Result<()> {
     let shim_path = Path::new(shim_dir).join("tooler-shim");
     let symlink_path = Path::new(shim_dir).join(tool_name);
@@ -393,4 +999,15 @@ fn create_tool_symlink(shim_dir: &str, tool_name: &str) -> Result<()> {
         tracing::info!("Created symlink {} -> {}", symlink_path.display(), shim_path.display());
     }
     Ok(())
+}
+
+/// Delete `tool_name`'s shim symlink (if any), so removing the last installed version of a tool
+/// also takes it off `PATH` instead of leaving a dangling symlink behind.
+fn remove_tool_symlink(shim_dir: &str, tool_name: &str) -> Result<()> {
+    let symlink_path = Path::new(shim_dir).join(tool_name);
+    if symlink_path.exists() || symlink_path.symlink_metadata().is_ok() {
+        fs::remove_file(&symlink_path)?;
+        tracing::info!("Removed symlink {}", symlink_path.display());
+    }
+    Ok(())
 }
\ No newline at end of file
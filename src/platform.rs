@@ -1,6 +1,7 @@
 use crate::types::*;
 use anyhow::Result;
 use std::collections::HashMap;
+use std::process::Command;
 
 pub fn get_system_info() -> PlatformInfo {
     let os = std::env::consts::OS.to_string();
@@ -13,9 +14,84 @@ pub fn get_system_info() -> PlatformInfo {
         _ => arch,
     };
 
+    let libc = if os == "linux" { detect_libc() } else { None };
+
     PlatformInfo {
         os,
         arch: normalized_arch,
+        libc,
+    }
+}
+
+/// Determine whether the host's C library is musl or glibc by probing for the loader each ships
+/// (`/lib/ld-musl-*` vs a `/lib*/ld-linux*` loader), falling back to `ldd --version`
+/// when neither loader path is found (e.g. a non-standard install layout).
+fn detect_libc() -> Option<String> {
+    let has_musl_loader = glob_exists("/lib/ld-musl-*") || glob_exists("/lib64/ld-musl-*");
+    if has_musl_loader {
+        return Some("musl".to_string());
+    }
+
+    let has_gnu_loader = glob_exists("/lib/x86_64-linux-gnu/ld-linux*")
+        || glob_exists("/lib/aarch64-linux-gnu/ld-linux*")
+        || glob_exists("/lib64/ld-linux*")
+        || glob_exists("/lib/ld-linux*");
+    if has_gnu_loader {
+        return Some("gnu".to_string());
+    }
+
+    let output = Command::new("ldd").arg("--version").output().ok()?;
+    let text = format!(
+        "{}{}",
+        String::from_utf8_lossy(&output.stdout),
+        String::from_utf8_lossy(&output.stderr)
+    )
+    .to_lowercase();
+    if text.contains("musl") {
+        Some("musl".to_string())
+    } else if text.contains("glibc") || text.contains("gnu") {
+        Some("gnu".to_string())
+    } else {
+        None
+    }
+}
+
+/// Whether any path matches the single-`*`-wildcard glob `pattern`, without pulling in a glob
+/// crate for this one narrow use.
+fn glob_exists(pattern: &str) -> bool {
+    let Some((dir, file_prefix_and_suffix)) = pattern.rsplit_once('/') else {
+        return false;
+    };
+    let Some((prefix, suffix)) = file_prefix_and_suffix.split_once('*') else {
+        return std::path::Path::new(pattern).exists();
+    };
+    let Ok(entries) = std::fs::read_dir(dir) else {
+        return false;
+    };
+    entries.flatten().any(|entry| {
+        let name = entry.file_name();
+        let name = name.to_string_lossy();
+        name.starts_with(prefix) && name.ends_with(suffix)
+    })
+}
+
+/// Score how well an asset's filename matches the host's libc so candidates can be ranked:
+/// positive for a matching token (`gnu`/`glibc` vs `musl`), negative for a mismatched one, and
+/// zero when the filename carries no libc token at all or the host's libc is unknown.
+fn libc_score(asset_name_lower: &str, system_libc: Option<&str>) -> i32 {
+    let Some(system_libc) = system_libc else {
+        return 0;
+    };
+
+    let names_musl = asset_name_lower.contains("musl");
+    let names_gnu = asset_name_lower.contains("gnu") || asset_name_lower.contains("glibc");
+
+    match (system_libc, names_musl, names_gnu) {
+        ("musl", true, _) => 1,
+        ("musl", false, true) => -1,
+        ("gnu", _, true) => 1,
+        ("gnu", true, false) => -1,
+        _ => 0,
     }
 }
 
@@ -24,6 +100,7 @@ pub fn find_asset_for_platform(
     _repo_full_name: &str,
     system_os: &str,
     system_arch: &str,
+    system_libc: Option<&str>,
 ) -> Result<Option<AssetInfo>> {
     tracing::trace!(
         "Looking for assets matching OS: '{}', ARCH: '{}'",
@@ -49,7 +126,9 @@ pub fn find_asset_for_platform(
 
     tracing::trace!("Available arch aliases: {:?}", arch_aliases);
 
-    let archive_exts = vec![".tar.gz", ".zip", ".tar.xz", ".tgz"];
+    let archive_exts = vec![
+        ".tar.gz", ".zip", ".tar.xz", ".tgz", ".tar.zst", ".tar.bz2", ".7z",
+    ];
     let package_exts = vec![".apk", ".deb", ".rpm"];
     let invalid_exts = vec![
         ".sha256", ".asc", ".sig", ".pem", ".pub", ".md", ".txt", ".pom", ".xml", ".json", ".whl",
@@ -89,7 +168,7 @@ pub fn find_asset_for_platform(
     for category in priority_order {
         if let Some(asset_list) = candidates.remove(category) {
             // Filter assets by exact OS and architecture match
-            let matching_assets: Vec<&GitHubAsset> = asset_list
+            let mut matching_assets: Vec<&GitHubAsset> = asset_list
                 .iter()
                 .filter(|asset| {
                     let name_lower = asset.name.to_lowercase();
@@ -121,6 +200,12 @@ pub fn find_asset_for_platform(
                 })
                 .collect();
 
+            // Within a category, prefer the asset whose libc token matches the host
+            // (`gnu`/`glibc` vs `musl`); demote a clear mismatch below libc-agnostic assets.
+            matching_assets.sort_by_key(|asset| {
+                std::cmp::Reverse(libc_score(&asset.name.to_lowercase(), system_libc))
+            });
+
             tracing::trace!(
                 "Category '{}': {} matching assets out of {}",
                 category,
@@ -133,6 +218,8 @@ pub fn find_asset_for_platform(
                 return Ok(Some(AssetInfo {
                     name: asset.name.clone(),
                     download_url: asset.browser_download_url.clone(),
+                    checksum: None,
+                    checksum_kind: None,
                 }));
             }
 
@@ -142,6 +229,8 @@ pub fn find_asset_for_platform(
                 return Ok(Some(AssetInfo {
                     name: asset.name.clone(),
                     download_url: asset.browser_download_url.clone(),
+                    checksum: None,
+                    checksum_kind: None,
                 }));
             }
         }
@@ -154,6 +243,8 @@ pub fn find_asset_for_platform(
             return Ok(Some(AssetInfo {
                 name: asset.name.clone(),
                 download_url: asset.browser_download_url.clone(),
+                checksum: None,
+                checksum_kind: None,
             }));
         }
     }
@@ -0,0 +1,205 @@
+//! Test-support helpers for fabricating mock forge releases and tool installs, so scenario
+//! tests don't have to hand-write config JSON and fake binaries inline (see the fuzzy-matching
+//! test in `tests/recovery_tests.rs` for the boilerplate this replaces).
+//! Only compiled in behind the `testkit` feature — never shipped in a release build.
+
+use crate::types::{ToolHealth, ToolHealthCheck, ToolInfo, ToolerConfig, ToolerSettings};
+use std::collections::HashMap;
+use std::fs;
+use std::io::{Read, Write};
+use std::net::{TcpListener, TcpStream};
+use std::path::Path;
+use std::thread;
+
+const MOCK_TIMESTAMP: &str = "2024-01-01T00:00:00Z";
+
+/// Fabricates a single installed tool: a fake executable on disk under a versioned
+/// `tools/github/<repo>__<arch>/v<version>/<name>` layout, plus the matching `ToolInfo` a
+/// `ConfigBuilder` can register it under. The returned `ToolInfo.executable_path` is what
+/// tooler actually resolves from, so this layout only needs to be plausible and
+/// self-consistent, not byte-for-byte identical to `install_or_update_tool`'s own.
+pub struct MockTool {
+    tool_name: String,
+    repo: String,
+    version: String,
+    binary_script: Option<String>,
+    pinned: bool,
+    health: Option<ToolHealth>,
+}
+
+impl MockTool {
+    pub fn new(repo: &str, version: &str) -> Self {
+        Self {
+            tool_name: repo.rsplit('/').next().unwrap_or(repo).to_string(),
+            repo: repo.to_string(),
+            version: version.trim_start_matches('v').to_string(),
+            binary_script: None,
+            pinned: false,
+            health: None,
+        }
+    }
+
+    /// Override the shell script written as the tool's executable. Defaults to a script that
+    /// just echoes `"<name> version <version>"`, whose first digit-leading token is what the
+    /// `--version` probe reads as the version.
+    pub fn with_binary(mut self, script: &str) -> Self {
+        self.binary_script = Some(script.to_string());
+        self
+    }
+
+    pub fn pinned(mut self) -> Self {
+        self.pinned = true;
+        self
+    }
+
+    /// Pre-seed `ToolInfo.health`, e.g. to exercise `doctor`/`--broken` filtering without
+    /// having to run `tooler check` first.
+    pub fn with_health(mut self, health: ToolHealth) -> Self {
+        self.health = Some(health);
+        self
+    }
+
+    /// Write the executable under `data_dir` and return the `(config_key, ToolInfo)` pair a
+    /// `ConfigBuilder` registers via `with_tool`.
+    pub fn install_into(self, data_dir: &Path) -> (String, ToolInfo) {
+        let arch = if cfg!(target_arch = "aarch64") { "arm64" } else { "amd64" };
+        let tool_dir = data_dir
+            .join("tools")
+            .join("github")
+            .join(format!("{}__{}", self.repo.replace('/', "__"), arch))
+            .join(format!("v{}", self.version));
+        fs::create_dir_all(&tool_dir).expect("testkit: failed to create mock tool dir");
+
+        let executable_path = tool_dir.join(&self.tool_name);
+        let script = self.binary_script.unwrap_or_else(|| {
+            format!("#!/bin/bash\necho \"{} version {}\"", self.tool_name, self.version)
+        });
+        fs::write(&executable_path, script).expect("testkit: failed to write mock binary");
+
+        #[cfg(unix)]
+        {
+            use std::os::unix::fs::PermissionsExt;
+            let mut perms = fs::metadata(&executable_path).unwrap().permissions();
+            perms.set_mode(0o755);
+            fs::set_permissions(&executable_path, perms).unwrap();
+        }
+
+        let info = ToolInfo {
+            tool_name: self.tool_name.clone(),
+            repo: self.repo.clone(),
+            version: self.version.clone(),
+            executable_path: executable_path.to_string_lossy().to_string(),
+            install_type: "binary".to_string(),
+            pinned: self.pinned,
+            installed_at: MOCK_TIMESTAMP.to_string(),
+            last_accessed: MOCK_TIMESTAMP.to_string(),
+            interpreter: None,
+            health: self.health.map(|state| ToolHealthCheck {
+                state,
+                checked_at: MOCK_TIMESTAMP.to_string(),
+            }),
+            quarantined: false,
+            files: vec![executable_path.to_string_lossy().to_string()],
+        };
+
+        (format!("{}@{}", self.repo, self.version), info)
+    }
+}
+
+/// Composes a `ToolerConfig` the way hand-written test JSON blobs used to, through a typed
+/// builder instead of a copy-pasted `format!` string.
+#[derive(Default)]
+pub struct ConfigBuilder {
+    tools: HashMap<String, ToolInfo>,
+    settings: ToolerSettings,
+}
+
+impl ConfigBuilder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn with_tool(mut self, key: impl Into<String>, info: ToolInfo) -> Self {
+        self.tools.insert(key.into(), info);
+        self
+    }
+
+    pub fn with_settings(mut self, configure: impl FnOnce(&mut ToolerSettings)) -> Self {
+        configure(&mut self.settings);
+        self
+    }
+
+    pub fn build(self) -> ToolerConfig {
+        ToolerConfig {
+            tools: self.tools,
+            settings: self.settings,
+        }
+    }
+
+    /// Build and write the config as JSON to `path`, in the layout `load_tool_configs` expects.
+    pub fn write_to(self, path: &Path) {
+        let config = self.build();
+        let json = serde_json::to_string_pretty(&config).expect("testkit: failed to serialize mock config");
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent).expect("testkit: failed to create config dir");
+        }
+        fs::write(path, json).expect("testkit: failed to write mock config");
+    }
+}
+
+/// A minimal in-process HTTP server serving canned responses, so update-check and download code
+/// paths can be exercised without reaching the network: any request path containing
+/// `/releases/download/` gets `asset_bytes` back, everything else gets `releases_json`.
+/// The listener thread runs for the life of the test process; it is not joined on drop.
+pub struct MockForge {
+    addr: std::net::SocketAddr,
+    _handle: thread::JoinHandle<()>,
+}
+
+impl MockForge {
+    pub fn start(releases_json: String, asset_bytes: Vec<u8>) -> Self {
+        let listener = TcpListener::bind("127.0.0.1:0").expect("testkit: failed to bind mock forge");
+        let addr = listener.local_addr().expect("testkit: failed to read mock forge addr");
+
+        let handle = thread::spawn(move || {
+            for stream in listener.incoming() {
+                let Ok(stream) = stream else { break };
+                handle_connection(stream, &releases_json, &asset_bytes);
+            }
+        });
+
+        Self { addr, _handle: handle }
+    }
+
+    /// Base URL (`http://127.0.0.1:<port>`) tests should point the forge client at.
+    pub fn base_url(&self) -> String {
+        format!("http://{}", self.addr)
+    }
+}
+
+fn handle_connection(mut stream: TcpStream, releases_json: &str, asset_bytes: &[u8]) {
+    let mut buf = [0u8; 4096];
+    let n = stream.read(&mut buf).unwrap_or(0);
+    let request = String::from_utf8_lossy(&buf[..n]);
+    let path = request
+        .lines()
+        .next()
+        .unwrap_or("")
+        .split_whitespace()
+        .nth(1)
+        .unwrap_or("/");
+
+    let (content_type, body): (&str, &[u8]) = if path.contains("/releases/download/") {
+        ("application/octet-stream", asset_bytes)
+    } else {
+        ("application/json", releases_json.as_bytes())
+    };
+
+    let response = format!(
+        "HTTP/1.1 200 OK\r\nContent-Type: {}\r\nContent-Length: {}\r\nConnection: close\r\n\r\n",
+        content_type,
+        body.len()
+    );
+    let _ = stream.write_all(response.as_bytes());
+    let _ = stream.write_all(body);
+}
@@ -1,9 +1,48 @@
 use serde::{Deserialize, Serialize};
 use std::fmt;
 
+/// Whether a version spec uses semver range syntax (`^1.2`, `~1.0.3`, `>=1.0,<2.0`, `*`, ...)
+/// rather than naming an exact tag.
+pub fn looks_like_version_range(version: &str) -> bool {
+    version.contains(['^', '~', '>', '<', '*', ','])
+}
+
+/// The git forge a tool's releases are hosted on.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum Forge {
+    GitHub,
+    GitLab,
+    /// Covers both Gitea and Forgejo, which share the same `/api/v1` release surface.
+    Gitea,
+}
+
+impl Forge {
+    /// The prefix used in a tool id (`<prefix>:owner/repo`) and recognized by `parse`.
+    fn prefix(self) -> &'static str {
+        match self {
+            Forge::GitHub => "github",
+            Forge::GitLab => "gitlab",
+            Forge::Gitea => "gitea",
+        }
+    }
+
+    fn from_prefix(prefix: &str) -> Option<Self> {
+        match prefix {
+            "github" => Some(Forge::GitHub),
+            "gitlab" => Some(Forge::GitLab),
+            "gitea" | "forgejo" => Some(Forge::Gitea),
+            _ => None,
+        }
+    }
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
 pub struct ToolIdentifier {
-    pub forge: String,
+    pub forge: Forge,
+    /// Base host for a self-hosted instance (e.g. `git.example.org`), `None` for the forge's
+    /// public SaaS instance (github.com, gitlab.com, ...).
+    pub host: Option<String>,
     pub author: String,
     pub repo: String,
     pub version: Option<String>,
@@ -11,11 +50,22 @@ pub struct ToolIdentifier {
 
 impl ToolIdentifier {
     /// Parse a tool identifier from various formats:
-    /// - "owner/repo" (default version)
+    /// - "owner/repo" (default version, defaults to the GitHub forge)
     /// - "owner/repo@v1.2.3" (specific version)
     /// - "repo" (short name, looks up in config)
     /// - "repo@v1.2.3" (short name with version)
+    /// - "gitlab:owner/repo" (explicit forge, public instance)
+    /// - "gitea:git.example.org/owner/repo" (explicit forge, self-hosted instance)
+    ///
+    /// Uses the GitHub forge when no `<forge>:` prefix is present; see
+    /// [`Self::parse_with_default_forge`] to honor a configured default instead.
     pub fn parse(tool_id: &str) -> Result<Self, String> {
+        Self::parse_with_default_forge(tool_id, "github")
+    }
+
+    /// Like [`Self::parse`], but falls back to `default_forge` (e.g. `settings.default_forge`)
+    /// rather than always GitHub when `tool_id` carries no explicit `<forge>:` prefix.
+    pub fn parse_with_default_forge(tool_id: &str, default_forge: &str) -> Result<Self, String> {
         // Handle @ for version
         let (repo_part, version_part) = if tool_id.contains('@') {
             let mut parts = tool_id.splitn(2, '@');
@@ -28,30 +78,65 @@ impl ToolIdentifier {
             (tool_id, Some("default".to_string()))
         };
 
-        // Parse repository part
-        let repo_parts: Vec<&str> = repo_part.split('/').collect();
-        let (author, repo) = match repo_parts.len() {
-            1 => {
-                // Short form like "act" - no author specified
-                ("unknown".to_string(), repo_parts[0].to_string())
-            }
-            2 => {
-                // Full form like "nektos/act"
-                (repo_parts[0].to_string(), repo_parts[1].to_string())
+        // Handle an explicit "<forge>:" prefix; fall back to the default forge when absent.
+        let (forge, repo_part) = match repo_part.split_once(':') {
+            Some((prefix, rest)) if Forge::from_prefix(prefix).is_some() => {
+                (Forge::from_prefix(prefix).unwrap(), rest)
             }
+            _ => (
+                Forge::from_prefix(default_forge).unwrap_or(Forge::GitHub),
+                repo_part,
+            ),
+        };
+
+        // Parse repository part, allowing a leading self-hosted host segment (one that looks
+        // like a domain, i.e. contains a '.') ahead of "owner/repo".
+        let segments: Vec<&str> = repo_part.split('/').collect();
+        let (host, author, repo) = match segments.len() {
+            1 => (None, "unknown".to_string(), segments[0].to_string()),
+            2 => (None, segments[0].to_string(), segments[1].to_string()),
+            3 if segments[0].contains('.') => (
+                Some(segments[0].to_string()),
+                segments[1].to_string(),
+                segments[2].to_string(),
+            ),
             _ => return Err(format!("Invalid repository format: {}", repo_part)),
         };
 
+        // A version spec that uses range syntax must be a valid requirement, so a typo like
+        // "^1.x" fails fast at parse time rather than silently falling back to an exact tag.
+        if let Some(v) = &version_part {
+            let v_clean = v.trim_start_matches('v');
+            if looks_like_version_range(v_clean) {
+                semver::VersionReq::parse(v_clean)
+                    .map_err(|e| format!("Invalid version requirement '{}': {}", v, e))?;
+            }
+        }
+
         Ok(ToolIdentifier {
-            forge: "github".to_string(),
+            forge,
+            host,
             author,
             repo,
             version: version_part,
         })
     }
 
-    /// Get: full repository string (author/repo)
+    /// Get: forge-qualified repository string. For the default GitHub forge with no self-hosted
+    /// host, this is plain `author/repo` so existing GitHub-only call sites are unaffected;
+    /// other forges (and self-hosted instances) prefix with `<forge>:[<host>/]` so lookups and
+    /// pins stay unambiguous across forges.
     pub fn full_repo(&self) -> String {
+        match (self.forge, &self.host) {
+            (Forge::GitHub, None) => self.repo_path(),
+            (forge, Some(host)) => format!("{}:{}/{}", forge.prefix(), host, self.repo_path()),
+            (forge, None) => format!("{}:{}", forge.prefix(), self.repo_path()),
+        }
+    }
+
+    /// Get: plain `author/repo` path, with no forge or host prefix — what a forge's own API
+    /// expects as the project identifier.
+    pub fn repo_path(&self) -> String {
         if self.author == "unknown" {
             self.repo.clone()
         } else {
@@ -88,9 +173,13 @@ impl ToolIdentifier {
         }
     }
 
-    /// Check if this is a version-pinned tool
+    /// Check if this is a version-pinned tool. An exact tag (`@1.2.3`) is pinned; a semver
+    /// range (`@^1.2`, `@>=1.0,<2.0`) is not, since a newer release can still satisfy it.
     pub fn is_pinned(&self) -> bool {
-        self.version.is_some() && self.version.as_deref().unwrap_or("default") != "default"
+        match self.version.as_deref() {
+            None | Some("default") => false,
+            Some(v) => !looks_like_version_range(v.trim_start_matches('v')),
+        }
     }
 }
 
@@ -103,3 +192,30 @@ impl fmt::Display for ToolIdentifier {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_keeps_range_text_and_rejects_garbage() {
+        let id = ToolIdentifier::parse("owner/repo@^1.2").unwrap();
+        assert_eq!(id.version.as_deref(), Some("^1.2"));
+        assert!(!id.is_pinned());
+
+        let err = ToolIdentifier::parse("owner/repo@^1.x").unwrap_err();
+        assert!(err.contains("Invalid version requirement"));
+    }
+
+    #[test]
+    fn test_is_pinned_exact_vs_range() {
+        let pinned = ToolIdentifier::parse("owner/repo@1.2.3").unwrap();
+        assert!(pinned.is_pinned());
+
+        let range = ToolIdentifier::parse("owner/repo@>=1.0,<2.0").unwrap();
+        assert!(!range.is_pinned());
+
+        let latest = ToolIdentifier::parse("owner/repo").unwrap();
+        assert!(!latest.is_pinned());
+    }
+}
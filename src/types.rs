@@ -13,6 +13,45 @@ pub struct ToolInfo {
     pub pinned: bool,
     pub installed_at: String,
     pub last_accessed: String,
+    /// For `python-venv` installs, the interpreter identity (`sys.base_prefix`/version) the
+    /// venv was built against, so a later interpreter change can be detected and rebuilt.
+    #[serde(default)]
+    pub interpreter: Option<String>,
+    /// Result of the most recent `tooler check`, if one has ever been run for this tool.
+    #[serde(default)]
+    pub health: Option<ToolHealthCheck>,
+    /// Set when an auto-update into this version regressed a previously-`Working` tool; kept
+    /// around for diagnosis but excluded from unpinned version resolution so it's never picked
+    /// as the active install again.
+    #[serde(default)]
+    pub quarantined: bool,
+    /// Every file this install wrote under its version directory, so `remove_tool` can delete
+    /// exactly what was created instead of relying on the version-directory convention. Empty
+    /// for entries predating this field, which fall back to that convention instead.
+    #[serde(default)]
+    pub files: Vec<String>,
+}
+
+/// Whether a tool's executable could be located and invoked the last time it was checked,
+/// ordered from least to most healthy (mirroring rustc's toolstate classification) so states
+/// can be compared with `<`/`>`. Defaults to `Broken` so a tool that has never
+/// been checked reads as unhealthy rather than silently passing.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, PartialOrd, Default)]
+#[serde(rename_all = "snake_case")]
+pub enum ToolHealth {
+    /// The executable doesn't exist on disk at all.
+    #[default]
+    Broken = 0,
+    /// The executable exists but the `--version` probe failed or its output couldn't be
+    /// parsed.
+    RunFail = 1,
+    Working = 2,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct ToolHealthCheck {
+    pub state: ToolHealth,
+    pub checked_at: String,
 }
 
 fn default_pinned() -> bool {
@@ -24,6 +63,42 @@ pub struct ToolerSettings {
     pub update_check_days: i32,
     pub auto_shim: bool,
     pub shim_dir: String,
+    #[serde(default)]
+    pub prefer_global: bool,
+    /// Checksum-manifest enforcement: `"true"` (require one), `"warn"` (verify if present,
+    /// otherwise proceed with a warning), or `"off"` (skip entirely).
+    #[serde(default = "default_verify_checksums")]
+    pub verify_checksums: String,
+    /// Forge a bare `owner/repo` tool id resolves against when it carries no `<forge>:` prefix.
+    #[serde(default = "default_forge")]
+    pub default_forge: String,
+    /// Path to an armored GPG public key used to verify a detached `.asc`/`.sig` release
+    /// signature when no checksum manifest is published.
+    #[serde(default)]
+    pub gpg_public_key_path: Option<String>,
+    /// GitHub API token used to raise the anonymous rate limit and reach private repos.
+    /// `GITHUB_TOKEN`/`GH_TOKEN` env vars take priority over this when both are set.
+    #[serde(default)]
+    pub github_token: Option<String>,
+    /// Never install into the system-wide shared tools directory, even when one exists and
+    /// is writable; always use the per-user data dir instead. Forced on automatically when
+    /// a `TOOLER_CI`/`CI` environment variable is detected.
+    #[serde(default)]
+    pub no_system_cache: bool,
+    /// When set, `check`/`doctor` merge their freshly-probed `ToolHealth` into a JSON snapshot
+    /// at this path, keyed by config key (mirroring rustc's `rust.save-toolstates`), so a team
+    /// can commit a baseline and diff it in CI. An update writes to it only when its regression
+    /// guard quarantines the new version; any other update leaves the snapshot untouched.
+    #[serde(default)]
+    pub save_toolstate_path: Option<String>,
+}
+
+fn default_verify_checksums() -> String {
+    "warn".to_string()
+}
+
+fn default_forge() -> String {
+    "github".to_string()
 }
 
 impl Default for ToolerSettings {
@@ -37,6 +112,13 @@ impl Default for ToolerSettings {
                 .join("bin")
                 .to_string_lossy()
                 .to_string(),
+            prefer_global: false,
+            verify_checksums: default_verify_checksums(),
+            default_forge: default_forge(),
+            gpg_public_key_path: None,
+            github_token: None,
+            no_system_cache: false,
+            save_toolstate_path: None,
         }
     }
 }
@@ -47,16 +129,43 @@ pub struct ToolerConfig {
     pub settings: ToolerSettings,
 }
 
+/// A tool config block that was soft-deleted via `remove --trash`, kept around so `restore`
+/// can put it back without re-downloading anything.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct TrashEntry {
+    /// The config key the tool lived under before it was trashed.
+    pub key: String,
+    pub info: ToolInfo,
+    pub removed_at: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, Default)]
+pub struct TrashStore {
+    /// Keyed by `removed_at` timestamp, since the same tool can be trashed more than once.
+    pub entries: HashMap<String, TrashEntry>,
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
 pub struct PlatformInfo {
     pub os: String,
     pub arch: String,
+    /// The host's C library on Linux (`"musl"` or `"gnu"`), used to steer asset selection away
+    /// from a glibc binary on an Alpine/musl host (or vice versa). `None` off Linux.
+    pub libc: Option<String>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
 pub struct AssetInfo {
     pub name: String,
     pub download_url: String,
+    /// Digest verified against a sibling checksum manifest, if any was found and matched; for a
+    /// `"gpg"` match, the name of the detached signature asset that verified instead.
+    #[serde(default)]
+    pub checksum: Option<String>,
+    /// What verified `checksum`: `"sha256"`, `"sha512"`, or `"gpg"` for a detached-signature
+    /// match. `None` means integrity verification was skipped or found nothing to check against.
+    #[serde(default)]
+    pub checksum_kind: Option<String>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
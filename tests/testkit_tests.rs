@@ -0,0 +1,37 @@
+#![cfg(feature = "testkit")]
+
+use std::process::Command;
+use tempfile::tempdir;
+use tooler::testkit::{ConfigBuilder, MockForge, MockTool};
+
+#[test]
+fn test_config_builder_round_trips_tool_entry() {
+    let root = tempdir().expect("failed to create temp dir");
+    let data_dir = root.path().join("data");
+    let config_path = root.path().join("config.json");
+
+    let (key, info) = MockTool::new("kubernetes/minikube", "1.31.0").install_into(&data_dir);
+    ConfigBuilder::new()
+        .with_tool(key.clone(), info)
+        .with_settings(|settings| settings.update_check_days = 5)
+        .write_to(&config_path);
+
+    let written = std::fs::read_to_string(&config_path).expect("failed to read mock config");
+    assert!(written.contains(&key));
+    assert!(written.contains("\"update_check_days\": 5"));
+}
+
+#[test]
+fn test_mock_forge_serves_canned_release() {
+    let forge = MockForge::start(
+        r#"[{"tag_name":"v1.2.3","assets":[{"name":"tool-linux-amd64","browser_download_url":"http://example.invalid/tool"}]}]"#.to_string(),
+        b"fake-binary-bytes".to_vec(),
+    );
+
+    let output = Command::new("curl")
+        .args(["-s", &format!("{}/repos/owner/tool/releases", forge.base_url())])
+        .output()
+        .expect("failed to run curl");
+    let body = String::from_utf8_lossy(&output.stdout);
+    assert!(body.contains("v1.2.3"));
+}